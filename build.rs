@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/*
+ * Generates a test case for every example fixture in input/examples, so that adding a
+ * fixture is enough to have it tested. See src/examples.rs for how the cases are run.
+ */
+fn main() {
+    let examples_dir = Path::new("input/examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut names: Vec<String> = match fs::read_dir(examples_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter_map(|path| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    names.sort();
+
+    let mut tests = String::new();
+    for name in names {
+        let test_name: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        tests.push_str(&format!(
+            "#[test]\nfn {}() {{\n    check_example({:?});\n}}\n\n",
+            test_name, name
+        ));
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out_path, tests).unwrap();
+}
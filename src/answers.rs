@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+
+// The default name of the answers file, resolved like the puzzle inputs
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/*
+ * The known correct answers for the real inputs, one per day and part. The answers file
+ * has one "<day> <part> <answer>" entry per row. Empty rows and rows starting with '#'
+ * are ignored.
+ */
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl KnownAnswers {
+    pub fn parse(input: &[String]) -> ParseResult<KnownAnswers> {
+        let mut answers = BTreeMap::new();
+        for (row_idx, row) in input.iter().enumerate() {
+            if row.trim().is_empty() || row.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = row.split_whitespace();
+            let mut next_token = |expected: &str| {
+                tokens
+                    .next()
+                    .ok_or_else(|| ParseError::end_of_input(row_idx, row.chars().count(), expected))
+            };
+            let day_token = next_token("a day")?;
+            let part_token = next_token("a part")?;
+            let answer = next_token("an answer")?;
+            if let Some(extra) = tokens.next() {
+                return Err(ParseError::new(row_idx, column_of(row, extra), "end of the row", extra));
+            }
+
+            let day: u8 = parse_token(day_token, row_idx, column_of(row, day_token), "a day")?;
+            let part: u8 = parse_token(part_token, row_idx, column_of(row, part_token), "a part")?;
+            if !(1..=25).contains(&day) {
+                return Err(ParseError::new(row_idx, column_of(row, day_token), "a day between 1 and 25", day_token));
+            }
+            if !(1..=2).contains(&part) {
+                return Err(ParseError::new(row_idx, column_of(row, part_token), "part 1 or 2", part_token));
+            }
+            if answers.insert((day, part), answer.to_string()).is_some() {
+                return Err(ParseError::new(row_idx, 0, "a day and part without an earlier answer", row));
+            }
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /*
+     * Compares an answer to the known one.
+     */
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    // There is no known answer for the day and part
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KnownAnswers, Verdict};
+
+    fn rows(input: &[&str]) -> Vec<String> {
+        input.iter().map(|r| r.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_answers_and_checks_them() {
+        let answers = KnownAnswers::parse(&rows(&[
+            "# day part answer",
+            "1 1 1020099",
+            "",
+            "21 2 kllgt,jrnqx",
+        ]))
+        .unwrap();
+
+        assert_eq!(Some("kllgt,jrnqx"), answers.get(21, 2));
+        assert_eq!(Verdict::Pass, answers.check(1, 1, "1020099"));
+        assert_eq!(Verdict::Fail, answers.check(1, 1, "1020098"));
+        assert_eq!(Verdict::Missing, answers.check(1, 2, "49214880"));
+    }
+
+    #[test]
+    fn reports_malformed_rows() {
+        let error = KnownAnswers::parse(&rows(&["1 1 5", "1 3 7"])).unwrap_err();
+        assert_eq!("line 2, column 3: expected part 1 or 2, found \"3\"", error.to_string());
+
+        let error = KnownAnswers::parse(&rows(&["2 1"])).unwrap_err();
+        assert_eq!("line 1, column 4: expected an answer, found end of input", error.to_string());
+
+        assert!(KnownAnswers::parse(&rows(&["2 1 5", "2 1 6"])).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * Solves bipartite assignment problems: every item on the left has to be assigned one
+ * of its candidates on the right, and no candidate can be assigned to two items. Items
+ * and candidates are both identified by their index.
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Assignment {
+    // No assignment satisfies the candidates
+    Unsolvable,
+    // The only assignment, with the candidate of every item
+    Unique(Vec<usize>),
+    // The assignments that satisfy the candidates, up to the limit of the search
+    Ambiguous(Vec<Vec<usize>>),
+}
+
+impl Assignment {
+    pub fn unique(self) -> Option<Vec<usize>> {
+        match self {
+            Assignment::Unique(assignment) => Some(assignment),
+            _ => None,
+        }
+    }
+}
+
+/*
+ * Finds every assignment for the given candidates of the items. Items with a single
+ * candidate left are assigned first, which is enough for well-formed puzzles, and the
+ * remaining choices are backtracked over. The number of assignments can grow
+ * factorially with the items, so use `assign_up_to` when only a few are needed.
+ */
+pub fn assign(candidates: &[Vec<usize>]) -> Assignment {
+    assign_up_to(candidates, usize::MAX)
+}
+
+/*
+ * Like `assign`, but stops searching after `limit` assignments. A limit of 2 is enough
+ * to tell a unique assignment from an ambiguous one.
+ */
+pub fn assign_up_to(candidates: &[Vec<usize>], limit: usize) -> Assignment {
+    let domains: Vec<Vec<usize>> = candidates
+        .iter()
+        .map(|item_candidates| {
+            let mut domain = item_candidates.clone();
+            domain.sort_unstable();
+            domain.dedup();
+            domain
+        })
+        .collect();
+
+    let mut solutions = Vec::new();
+    if limit > 0 {
+        search(domains, limit, &mut solutions);
+    }
+    match solutions.len() {
+        0 => Assignment::Unsolvable,
+        1 => Assignment::Unique(solutions.pop().unwrap()),
+        _ => Assignment::Ambiguous(solutions),
+    }
+}
+
+fn search(domains: Vec<Vec<usize>>, limit: usize, solutions: &mut Vec<Vec<usize>>) {
+    let domains = match propagate(domains) {
+        Some(domains) => domains,
+        None => return,
+    };
+    // Branch on the undecided item with the fewest candidates
+    let undecided = (0..domains.len())
+        .filter(|item| domains[*item].len() > 1)
+        .min_by_key(|item| domains[*item].len());
+    match undecided {
+        None => solutions.push(domains.iter().map(|domain| domain[0]).collect()),
+        Some(item) => {
+            for candidate in &domains[item] {
+                if solutions.len() >= limit {
+                    return;
+                }
+                let mut next = domains.clone();
+                next[item] = vec![*candidate];
+                search(next, limit, solutions);
+            }
+        }
+    }
+}
+
+/*
+ * Removes the candidates of the items that only have one left from all other items,
+ * until nothing changes. Returns None if an item runs out of candidates.
+ */
+fn propagate(mut domains: Vec<Vec<usize>>) -> Option<Vec<Vec<usize>>> {
+    loop {
+        if domains.iter().any(Vec::is_empty) {
+            return None;
+        }
+        let mut changed = false;
+        for item in 0..domains.len() {
+            if domains[item].len() != 1 {
+                continue;
+            }
+            let candidate = domains[item][0];
+            for (other, domain) in domains.iter_mut().enumerate() {
+                if other != item && domain.contains(&candidate) {
+                    domain.retain(|c| *c != candidate);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return Some(domains);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assign, assign_up_to, Assignment};
+
+    #[test]
+    fn elimination_finds_unique_assignments() {
+        let candidates = vec![vec![0, 1, 2], vec![1], vec![1, 2]];
+        assert_eq!(Assignment::Unique(vec![0, 1, 2]), assign(&candidates));
+        // There may be more candidates than items
+        let candidates = vec![vec![3, 0, 3], vec![0]];
+        assert_eq!(Assignment::Unique(vec![3, 0]), assign(&candidates));
+        assert_eq!(Assignment::Unique(vec![]), assign(&[]));
+    }
+
+    #[test]
+    fn backtracking_lists_all_assignments() {
+        // No item has a single candidate, and only one choice for item 0 works out
+        let candidates = vec![vec![0, 1], vec![1, 2], vec![1, 2], vec![0, 3]];
+        let assignment = assign(&candidates);
+        assert_eq!(
+            Assignment::Ambiguous(vec![vec![0, 1, 2, 3], vec![0, 2, 1, 3]]),
+            assignment
+        );
+        assert_eq!(None, assignment.unique());
+    }
+
+    #[test]
+    fn limited_search_stops_early() {
+        // 20 items that can take any slot have 20! assignments
+        let candidates = vec![(0..20).collect::<Vec<usize>>(); 20];
+        match assign_up_to(&candidates, 2) {
+            Assignment::Ambiguous(assignments) => assert_eq!(2, assignments.len()),
+            assignment => panic!("expected an ambiguous assignment, got {:?}", assignment),
+        }
+        let candidates = vec![vec![0, 1], vec![1]];
+        assert_eq!(Assignment::Unique(vec![0, 1]), assign_up_to(&candidates, 2));
+    }
+
+    #[test]
+    fn conflicts_are_unsolvable() {
+        let candidates = vec![vec![0, 1], vec![0, 1], vec![1, 0]];
+        assert_eq!(Assignment::Unsolvable, assign(&candidates));
+        assert_eq!(Assignment::Unsolvable, assign(&[vec![0], vec![]]));
+    }
+}
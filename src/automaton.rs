@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::error::{ParseError, ParseResult};
+use crate::hex::Hex;
+
+/*
+ * The shape of the space the cells live in: which cells are the neighbours of a cell.
+ */
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell>;
+}
+
+/*
+ * An infinite square lattice of N dimensions, where every cell has the 3^N - 1 cells
+ * around it, diagonals included, as neighbours.
+ */
+pub struct SquareLattice<const N: usize> {
+    offsets: Vec<[i32; N]>,
+}
+
+impl<const N: usize> SquareLattice<N> {
+    pub fn new() -> SquareLattice<N> {
+        let mut offsets = vec![[0; N]];
+        for dimension in 0..N {
+            offsets = offsets
+                .iter()
+                .flat_map(|offset| {
+                    (-1..=1).map(move |d| {
+                        let mut offset = *offset;
+                        offset[dimension] = d;
+                        offset
+                    })
+                })
+                .collect();
+        }
+        // A cell is not its own neighbour
+        offsets.retain(|offset| offset.iter().any(|d| *d != 0));
+        SquareLattice { offsets }
+    }
+}
+
+impl<const N: usize> Default for SquareLattice<N> {
+    fn default() -> Self {
+        SquareLattice::new()
+    }
+}
+
+impl<const N: usize> Topology for SquareLattice<N> {
+    type Cell = [i32; N];
+
+    fn neighbours(&self, cell: &[i32; N]) -> Vec<[i32; N]> {
+        self.offsets
+            .iter()
+            .map(|offset| {
+                let mut neighbour = *cell;
+                for (coord, d) in neighbour.iter_mut().zip(offset) {
+                    *coord += d;
+                }
+                neighbour
+            })
+            .collect()
+    }
+}
+
+/*
+ * An infinite hexagonal grid, where every cell has six neighbours.
+ */
+pub struct HexLattice;
+
+impl Topology for HexLattice {
+    type Cell = Hex;
+
+    fn neighbours(&self, cell: &Hex) -> Vec<Hex> {
+        cell.neighbours().to_vec()
+    }
+}
+
+/*
+ * A finite two-dimensional grid of (x, y) cells with the eight surrounding cells as
+ * neighbours. The cells outside the grid are always dead.
+ */
+pub struct BoundedGrid {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Topology for BoundedGrid {
+    type Cell = (usize, usize);
+
+    fn neighbours(&self, cell: &(usize, usize)) -> Vec<(usize, usize)> {
+        let (x, y) = *cell;
+        // A grid without rows or columns has no cells to be neighbours
+        let (max_x, max_y) = match (self.width.checked_sub(1), self.height.checked_sub(1)) {
+            (Some(max_x), Some(max_y)) => (max_x, max_y),
+            _ => return Vec::new(),
+        };
+        let mut neighbours = Vec::with_capacity(8);
+        for ny in y.saturating_sub(1)..=(y + 1).min(max_y) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(max_x) {
+                if (nx, ny) != (x, y) {
+                    neighbours.push((nx, ny));
+                }
+            }
+        }
+        neighbours
+    }
+}
+
+/*
+ * A rule in B/S notation, e.g. "B3/S23": a dead cell comes alive if its number of live
+ * neighbours is one of the birth counts, and a live cell stays alive if its number is
+ * one of the survival counts.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    // Bit n is set if n live neighbours cause a birth / let a cell survive
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    pub fn parse(notation: &str) -> ParseResult<Rule> {
+        let (birth_part, survival_part) = match notation.find('/') {
+            Some(idx) => (&notation[..idx], &notation[idx + 1..]),
+            None => return Err(ParseError::end_of_input(0, notation.chars().count(), "'/'")),
+        };
+        let birth = Rule::parse_counts(birth_part, 'B', 0)?;
+        let survival = Rule::parse_counts(survival_part, 'S', birth_part.chars().count() + 1)?;
+        // Without any live neighbours, a birth could happen anywhere in an infinite space
+        if birth & 1 != 0 {
+            return Err(ParseError::new(0, 1, "a birth count above 0", "0"));
+        }
+        Ok(Rule { birth, survival })
+    }
+
+    fn parse_counts(part: &str, prefix: char, first_col: usize) -> ParseResult<u16> {
+        let mut chars = part.chars();
+        match chars.next() {
+            Some(c) if c == prefix => {}
+            Some(c) => return Err(ParseError::new(0, first_col, &format!("'{}'", prefix), &c.to_string())),
+            None => return Err(ParseError::end_of_input(0, first_col, &format!("'{}'", prefix))),
+        }
+        let mut counts = 0;
+        for (idx, c) in chars.enumerate() {
+            match c.to_digit(10) {
+                Some(count) => counts |= 1 << count,
+                None => return Err(ParseError::new(0, first_col + 1 + idx, "a neighbour count", &c.to_string())),
+            }
+        }
+        Ok(counts)
+    }
+
+    pub fn is_born(&self, live_neighbours: usize) -> bool {
+        live_neighbours < 16 && self.birth & (1 << live_neighbours) != 0
+    }
+
+    pub fn survives(&self, live_neighbours: usize) -> bool {
+        live_neighbours < 16 && self.survival & (1 << live_neighbours) != 0
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |bits: u16| (0..10).filter(|n| bits & (1 << n) != 0).map(|n| n.to_string()).collect::<String>();
+        write!(f, "B{}/S{}", counts(self.birth), counts(self.survival))
+    }
+}
+
+/*
+ * A cellular automaton that only stores its live cells, so the space can be infinite.
+ */
+pub struct Automaton<T: Topology> {
+    topology: T,
+    rule: Rule,
+    live_cells: HashSet<T::Cell>,
+    generation: u32,
+}
+
+impl<T: Topology> Automaton<T> {
+    pub fn new<I>(topology: T, rule: Rule, live_cells: I) -> Automaton<T>
+    where
+        I: IntoIterator<Item = T::Cell>,
+    {
+        Automaton {
+            topology,
+            rule,
+            live_cells: live_cells.into_iter().collect(),
+            generation: 0,
+        }
+    }
+
+    /*
+     * Advances the automaton by one generation.
+     */
+    pub fn step(&mut self) {
+        // Initialize the counts with the live cells (and 0 neighbours) so they are
+        // guaranteed to be evaluated even without any live neighbours
+        let mut live_neighbour_counts: HashMap<T::Cell, usize> = self.live_cells.iter().map(|c| (*c, 0)).collect();
+        for cell in &self.live_cells {
+            for neighbour in self.topology.neighbours(cell) {
+                *live_neighbour_counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let rule = self.rule;
+        let live_cells = &self.live_cells;
+        self.live_cells = live_neighbour_counts
+            .into_iter()
+            .filter(|(cell, count)| match live_cells.contains(cell) {
+                true => rule.survives(*count),
+                false => rule.is_born(*count),
+            })
+            .map(|(cell, _)| cell)
+            .collect();
+        self.generation += 1;
+    }
+
+    pub fn run(&mut self, generations: u32) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    pub fn live_cells(&self) -> &HashSet<T::Cell> {
+        &self.live_cells
+    }
+
+    pub fn population(&self) -> usize {
+        self.live_cells.len()
+    }
+
+    // The number of generations simulated so far
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Automaton, BoundedGrid, HexLattice, Rule, SquareLattice, Topology};
+    use crate::hex::Hex;
+
+    #[test]
+    fn rules_use_bs_notation() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        assert!(rule.is_born(3));
+        assert!(!rule.is_born(2));
+        assert!(rule.survives(2) && rule.survives(3));
+        assert!(!rule.survives(80));
+        assert_eq!("B2/S12", Rule::parse("B2/S12").unwrap().to_string());
+
+        let error = Rule::parse("B3/X23").unwrap_err();
+        assert_eq!("line 1, column 4: expected 'S', found \"X\"", error.to_string());
+        let error = Rule::parse("B3a/S23").unwrap_err();
+        assert_eq!((1, 3), (error.line, error.column));
+        assert!(Rule::parse("B3S23").is_err());
+        assert!(Rule::parse("B03/S23").is_err());
+    }
+
+    #[test]
+    fn square_lattices_have_all_diagonal_neighbours() {
+        assert_eq!(8, SquareLattice::<2>::new().neighbours(&[0, 0]).len());
+        assert_eq!(80, SquareLattice::<4>::new().neighbours(&[0, 0, 0, 0]).len());
+        assert_eq!(3, BoundedGrid { width: 5, height: 5 }.neighbours(&(0, 0)).len());
+        assert!(BoundedGrid { width: 0, height: 3 }.neighbours(&(0, 0)).is_empty());
+        assert_eq!(6, HexLattice.neighbours(&Hex::ORIGIN).len());
+    }
+
+    #[test]
+    fn blinkers_oscillate() {
+        let blinker = vec![[0, -1], [0, 0], [0, 1]];
+        let mut automaton = Automaton::new(SquareLattice::<2>::new(), Rule::parse("B3/S23").unwrap(), blinker.clone());
+
+        automaton.step();
+        let expected: HashSet<[i32; 2]> = vec![[-1, 0], [0, 0], [1, 0]].into_iter().collect();
+        assert_eq!(&expected, automaton.live_cells());
+        automaton.step();
+        assert_eq!(&blinker.into_iter().collect::<HashSet<[i32; 2]>>(), automaton.live_cells());
+        assert_eq!(2, automaton.generation());
+    }
+
+    #[test]
+    fn bounded_grids_cut_off_the_edges() {
+        // A glider heading to the bottom right corner ends up as a still block
+        let glider = vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut automaton = Automaton::new(BoundedGrid { width: 6, height: 6 }, Rule::parse("B3/S23").unwrap(), glider);
+        automaton.run(40);
+
+        let block: HashSet<(usize, usize)> = vec![(4, 4), (5, 4), (4, 5), (5, 5)].into_iter().collect();
+        assert_eq!(&block, automaton.live_cells());
+    }
+}
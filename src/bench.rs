@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::Solver;
+
+// The default history file, relative to the working directory
+pub const HISTORY_FILE: &str = ".aoc-bench-history";
+// A stage is flagged as a regression if its median is this many percent slower
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/*
+ * A timed step of solving a day: parsing the input, or solving one of the parts.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Stage {
+    fn parse(token: &str, row: usize, col: usize) -> ParseResult<Stage> {
+        match token {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part(1)),
+            "part2" => Ok(Stage::Part(2)),
+            _ => Err(ParseError::new(row, col, "\"parse\", \"part1\" or \"part2\"", token)),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/*
+ * The durations of one stage of a day over all the benchmark iterations.
+ */
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    // Sorted from the fastest to the slowest
+    samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub fn new(day: u8, stage: Stage, mut samples: Vec<Duration>) -> Measurement {
+        assert!(!samples.is_empty(), "A measurement needs at least one sample");
+        samples.sort();
+        Measurement { day, stage, samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+/*
+ * Solves a day `iterations` times and measures the parsing and every part separately.
+ */
+pub fn benchmark(solver: &Solver, input: &str, iterations: u32) -> ParseResult<Vec<Measurement>> {
+    assert!(iterations > 0, "At least one iteration is needed");
+
+    let mut parse_samples = Vec::new();
+    let mut part_samples: Vec<Vec<Duration>> = vec![Vec::new(); solver.parts as usize];
+    for _ in 0..iterations {
+        let timed = solver.solve_timed(input)?;
+        parse_samples.push(timed.parse_time);
+        for (samples, (_, duration)) in part_samples.iter_mut().zip(timed.parts) {
+            samples.push(duration);
+        }
+    }
+
+    let mut measurements = vec![Measurement::new(solver.day, Stage::Parse, parse_samples)];
+    for (idx, samples) in part_samples.into_iter().enumerate() {
+        measurements.push(Measurement::new(solver.day, Stage::Part(idx as u8 + 1), samples));
+    }
+    Ok(measurements)
+}
+
+/*
+ * The medians of earlier benchmark runs. The history file has one
+ * "<timestamp> <day> <stage> <median in nanoseconds>" entry per row, oldest first.
+ */
+#[derive(Debug, Default)]
+pub struct History {
+    latest: HashMap<(u8, Stage), Duration>,
+}
+
+impl History {
+    pub fn parse(input: &[String]) -> ParseResult<History> {
+        let mut latest = HashMap::new();
+        for (row_idx, row) in input.iter().enumerate() {
+            if row.trim().is_empty() {
+                continue;
+            }
+
+            let tokens: Vec<&str> = row.split_whitespace().collect();
+            if tokens.len() != 4 {
+                let col = tokens.get(4).map_or(row.chars().count(), |t| column_of(row, t));
+                return Err(ParseError::new(row_idx, col, "\"<timestamp> <day> <stage> <nanoseconds>\"", row));
+            }
+            parse_token::<u64>(tokens[0], row_idx, column_of(row, tokens[0]), "a timestamp")?;
+            let day: u8 = parse_token(tokens[1], row_idx, column_of(row, tokens[1]), "a day")?;
+            let stage = Stage::parse(tokens[2], row_idx, column_of(row, tokens[2]))?;
+            let nanos: u64 = parse_token(tokens[3], row_idx, column_of(row, tokens[3]), "a duration")?;
+
+            // Later rows override the earlier ones
+            latest.insert((day, stage), Duration::from_nanos(nanos));
+        }
+
+        Ok(History { latest })
+    }
+
+    /*
+     * Returns the median of the latest earlier run of the day's stage.
+     */
+    pub fn previous(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.latest.get(&(day, stage)).copied()
+    }
+
+    /*
+     * Formats a measurement as a history file row.
+     */
+    pub fn row(timestamp: u64, measurement: &Measurement) -> String {
+        format!(
+            "{} {} {} {}",
+            timestamp,
+            measurement.day,
+            measurement.stage,
+            measurement.median().as_nanos()
+        )
+    }
+}
+
+/*
+ * Returns the relative change from the previous median to the current one in percent.
+ */
+pub fn change_percent(previous: Duration, current: Duration) -> f64 {
+    if previous.as_nanos() == 0 {
+        return 0.0;
+    }
+    (current.as_secs_f64() - previous.as_secs_f64()) / previous.as_secs_f64() * 100.0
+}
+
+pub fn is_regression(previous: Duration, current: Duration, threshold: f64) -> bool {
+    change_percent(previous, current) > threshold
+}
+
+/*
+ * Formats a duration with a unit that keeps the value readable, e.g. "12.3ms".
+ */
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_duration, is_regression, History, Measurement, Stage};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn measurement_statistics() {
+        let measurement = Measurement::new(15, Stage::Part(2), vec![ms(9), ms(3), ms(4), ms(8)]);
+        assert_eq!(ms(3), measurement.min());
+        assert_eq!(ms(6), measurement.median());
+        assert_eq!(ms(6), measurement.mean());
+    }
+
+    #[test]
+    fn history_keeps_the_latest_runs() {
+        let measurement = Measurement::new(23, Stage::Parse, vec![ms(2)]);
+        let rows = vec![
+            "1608000000 23 part1 5000000".to_string(),
+            "1608000000 23 parse 1000".to_string(),
+            History::row(1608000100, &measurement),
+        ];
+        let history = History::parse(&rows).unwrap();
+
+        assert_eq!(Some(ms(5)), history.previous(23, Stage::Part(1)));
+        assert_eq!(Some(ms(2)), history.previous(23, Stage::Parse));
+        assert_eq!(None, history.previous(23, Stage::Part(2)));
+    }
+
+    #[test]
+    fn history_reports_malformed_rows() {
+        let error = History::parse(&["1608000000 23 part3 5000".to_string()]).unwrap_err();
+        assert_eq!((1, 15), (error.line, error.column));
+    }
+
+    #[test]
+    fn regressions_exceed_the_threshold() {
+        assert!(is_regression(ms(100), ms(111), 10.0));
+        assert!(!is_regression(ms(100), ms(109), 10.0));
+        assert!(!is_regression(ms(100), ms(50), 10.0));
+    }
+
+    #[test]
+    fn durations_are_formatted_with_units() {
+        assert_eq!("512ns", format_duration(Duration::from_nanos(512)));
+        assert_eq!("12.3ms", format_duration(Duration::from_micros(12_345)));
+        assert_eq!("1.50s", format_duration(ms(1500)));
+    }
+}
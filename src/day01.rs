@@ -0,0 +1,365 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::{parse_token, ParseResult};
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Output1 = Option<u64>;
+    type Output2 = Option<u64>;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<u32>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| parse_token(r, idx, 0, "an expense amount"))
+            .collect()
+    }
+
+    fn part1(input: &Vec<u32>) -> Option<u64> {
+        find_k_sum(input, 2, 2020)?.product()
+    }
+
+    fn part2(input: &Vec<u32>) -> Option<u64> {
+        find_k_sum(input, 3, 2020)?.product()
+    }
+}
+
+/*
+ * Entries of the expense report that sum to a target. The values are in ascending order
+ * and the indices are those of the values in the report.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<u32>,
+}
+
+impl Combination {
+    pub fn sum(&self) -> u64 {
+        self.values.iter().map(|v| *v as u64).sum()
+    }
+
+    // None if the product does not fit in a u64
+    pub fn product(&self) -> Option<u64> {
+        self.values.iter().try_fold(1u64, |product, v| product.checked_mul(*v as u64))
+    }
+}
+
+/*
+ * Finds k distinct entries that sum to the target. The entries are sorted once, after
+ * which the last two entries are searched with two pointers, so it takes O(n^(k-1)) time
+ * for k >= 2.
+ */
+pub fn find_k_sum(entries: &[u32], k: usize, target: u64) -> Option<Combination> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|idx| (entries[*idx], *idx));
+    let sorted: Vec<u64> = order.iter().map(|idx| entries[*idx] as u64).collect();
+
+    let mut chosen = Vec::with_capacity(k);
+    if !find_k_sum_sorted(&sorted, 0, k, target, &mut chosen) {
+        return None;
+    }
+    Some(Combination {
+        indices: chosen.iter().map(|pos| order[*pos]).collect(),
+        values: chosen.iter().map(|pos| entries[order[*pos]]).collect(),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombinationOptions {
+    // Whether a combination may contain the same value more than once, from different
+    // entries
+    pub allow_repeated_values: bool,
+}
+
+impl Default for CombinationOptions {
+    fn default() -> Self {
+        CombinationOptions {
+            allow_repeated_values: true,
+        }
+    }
+}
+
+/*
+ * Returns an iterator over every combination of k distinct entries that sums to the
+ * target. Combinations with the same values are only yielded once, using the first
+ * entries with those values, and they come in lexicographic order of their values.
+ */
+pub fn combinations(entries: &[u32], k: usize, target: u64, options: CombinationOptions) -> Combinations {
+    let mut indices_by_value: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for (idx, value) in entries.iter().enumerate() {
+        indices_by_value.entry(*value).or_default().push(idx);
+    }
+    let (values, indices): (Vec<u64>, Vec<Vec<usize>>) =
+        indices_by_value.into_iter().map(|(value, indices)| (value as u64, indices)).unzip();
+    let positions = values.iter().enumerate().map(|(pos, value)| (*value, pos)).collect();
+
+    Combinations {
+        values,
+        indices,
+        positions,
+        k,
+        target,
+        options,
+        prefix: Vec::with_capacity(k),
+        started: false,
+        done: false,
+    }
+}
+
+/*
+ * The combinations are searched for lazily: all but the last value are enumerated like
+ * an odometer over the distinct values, and the last one is looked up.
+ */
+pub struct Combinations {
+    // The distinct values in ascending order, and the indices of the entries with them
+    values: Vec<u64>,
+    indices: Vec<Vec<usize>>,
+    // The position of every value in `values`
+    positions: HashMap<u64, usize>,
+    k: usize,
+    target: u64,
+    options: CombinationOptions,
+    // The positions of all but the last value of the current combination
+    prefix: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+impl Combinations {
+    // How many times the value at the position can be in a combination
+    fn capacity(&self, pos: usize) -> usize {
+        match self.options.allow_repeated_values {
+            true => self.indices[pos].len(),
+            false => 1,
+        }
+    }
+
+    fn uses(&self, pos: usize) -> usize {
+        self.prefix.iter().filter(|p| **p == pos).count()
+    }
+
+    fn prefix_sum(&self) -> u64 {
+        self.prefix.iter().map(|pos| self.values[*pos]).sum()
+    }
+
+    /*
+     * Moves the prefix to the next valid one, trying the given position at the end of
+     * the current prefix first. Returns false if there are no more prefixes.
+     */
+    fn advance(&mut self, mut candidate: usize) -> bool {
+        loop {
+            // The rest of the combination only has values at least as large as the
+            // candidate, so larger candidates cannot fit either if this one does not
+            let remaining = (self.k - self.prefix.len()) as u64;
+            let fits = candidate < self.values.len()
+                && self.prefix_sum() + self.values[candidate].saturating_mul(remaining) <= self.target;
+            if !fits {
+                match self.prefix.pop() {
+                    Some(pos) => candidate = pos + 1,
+                    None => return false,
+                }
+            } else if self.uses(candidate) == self.capacity(candidate) {
+                candidate += 1;
+            } else {
+                self.prefix.push(candidate);
+                if self.prefix.len() == self.k - 1 {
+                    return true;
+                }
+            }
+        }
+    }
+
+    // The last value that completes the prefix, if there is one
+    fn complete(&self) -> Option<Combination> {
+        let last = *self.positions.get(&(self.target - self.prefix_sum()))?;
+        if self.prefix.last().is_some_and(|pos| last < *pos) || self.uses(last) == self.capacity(last) {
+            return None;
+        }
+
+        let mut combination = Combination {
+            indices: Vec::with_capacity(self.k),
+            values: Vec::with_capacity(self.k),
+        };
+        for (nth, pos) in self.prefix.iter().chain(std::iter::once(&last)).enumerate() {
+            // Repeated values use the next entry with the value
+            let repeat = combination.values[..nth].iter().filter(|v| **v as u64 == self.values[*pos]).count();
+            combination.indices.push(self.indices[*pos][repeat]);
+            combination.values.push(self.values[*pos] as u32);
+        }
+        Some(combination)
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Combination;
+
+    fn next(&mut self) -> Option<Combination> {
+        if self.k == 0 {
+            // Only the empty combination sums to 0
+            let found = !self.done && self.target == 0;
+            self.done = true;
+            return match found {
+                true => Some(Combination { indices: vec![], values: vec![] }),
+                false => None,
+            };
+        }
+        while !self.done {
+            let has_prefix = match (self.started, self.prefix.pop()) {
+                // A single value has an empty prefix, which is only tried once
+                (false, _) if self.k == 1 => true,
+                (false, _) => self.advance(0),
+                (true, Some(pos)) => self.advance(pos + 1),
+                (true, None) => false,
+            };
+            self.started = true;
+            if !has_prefix {
+                self.done = true;
+            } else if let Some(combination) = self.complete() {
+                return Some(combination);
+            }
+        }
+        None
+    }
+}
+
+/*
+ * Searches the sorted values from `start` on for k values that sum to the target, and
+ * pushes their positions to `chosen` if there are.
+ */
+fn find_k_sum_sorted(sorted: &[u64], start: usize, k: usize, target: u64, chosen: &mut Vec<usize>) -> bool {
+    match k {
+        0 => target == 0,
+        1 => match sorted[start..].binary_search(&target) {
+            Ok(offset) => {
+                chosen.push(start + offset);
+                true
+            }
+            Err(_) => false,
+        },
+        2 => {
+            if sorted.len() < start + 2 {
+                return false;
+            }
+            let (mut low, mut high) = (start, sorted.len() - 1);
+            while low < high {
+                let sum = sorted[low] + sorted[high];
+                if sum == target {
+                    chosen.extend(&[low, high]);
+                    return true;
+                } else if sum < target {
+                    low += 1;
+                } else {
+                    high -= 1;
+                }
+            }
+            false
+        }
+        _ => {
+            for first in start..sorted.len() {
+                // The rest of the values are at least as large as this one
+                if sorted[first].saturating_mul(k as u64) > target {
+                    break;
+                }
+                chosen.push(first);
+                if find_k_sum_sorted(sorted, first + 1, k - 1, target - sorted[first], chosen) {
+                    return true;
+                }
+                chosen.pop();
+            }
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{combinations, find_k_sum, Combination, CombinationOptions};
+
+    #[test]
+    fn entries_are_not_reused() {
+        // 1010 + 1010 would need the same entry twice
+        assert_eq!(None, find_k_sum(&[1010, 5, 7], 2, 2020));
+        let combination = find_k_sum(&[1010, 5, 1010], 2, 2020).unwrap();
+        assert_eq!(vec![0, 2], combination.indices);
+    }
+
+    #[test]
+    fn any_k_and_target_can_be_searched() {
+        let entries = [1721, 979, 366, 299, 675, 1456];
+        let combination = find_k_sum(&entries, 3, 2020).unwrap();
+        assert_eq!(
+            Combination {
+                indices: vec![2, 4, 1],
+                values: vec![366, 675, 979],
+            },
+            combination
+        );
+        assert_eq!(Some(241861950), combination.product());
+
+        let combination = find_k_sum(&entries, 4, 299 + 366 + 979 + 1721).unwrap();
+        assert_eq!(vec![3, 2, 1, 0], combination.indices);
+        assert_eq!(3365, combination.sum());
+        assert_eq!(Some(vec![2]), find_k_sum(&entries, 1, 366).map(|c| c.indices));
+        assert_eq!(None, find_k_sum(&entries, 7, 5496));
+        assert_eq!(Some(1), find_k_sum(&entries, 0, 0).and_then(|c| c.product()));
+
+        let combination = find_k_sum(&[u32::MAX, u32::MAX, u32::MAX, 1], 3, 3 * u32::MAX as u64).unwrap();
+        assert_eq!(None, combination.product());
+    }
+
+    fn all_values(entries: &[u32], k: usize, target: u64, allow_repeated_values: bool) -> Vec<Vec<u32>> {
+        let options = CombinationOptions { allow_repeated_values };
+        combinations(entries, k, target, options).map(|c| c.values).collect()
+    }
+
+    #[test]
+    fn combinations_are_distinct_and_ordered() {
+        let entries = [1010, 2015, 1010, 1000, 5, 1020, 1010, 1000];
+        assert_eq!(
+            vec![vec![5, 2015], vec![1000, 1020], vec![1010, 1010]],
+            all_values(&entries, 2, 2020, true)
+        );
+        assert_eq!(vec![vec![5, 2015], vec![1000, 1020]], all_values(&entries, 2, 2020, false));
+        assert_eq!(
+            vec![vec![5, 1010, 2015], vec![1000, 1010, 1020], vec![1010, 1010, 1010]],
+            all_values(&entries, 3, 3030, true)
+        );
+        assert_eq!(
+            vec![vec![5, 1000, 1020, 2015], vec![5, 1010, 1010, 2015], vec![1000, 1010, 1010, 1020]],
+            all_values(&entries, 4, 4040, true)
+        );
+        assert_eq!(vec![vec![5, 1000, 1020, 2015]], all_values(&entries, 4, 4040, false));
+
+        // Repeated values take the first entries with the value
+        let options = CombinationOptions::default();
+        let last = combinations(&entries, 2, 2020, options).last().unwrap();
+        assert_eq!(vec![0, 2], last.indices);
+        let first = combinations(&entries, 3, 2025, options).next().unwrap();
+        assert_eq!(
+            Combination {
+                indices: vec![4, 3, 5],
+                values: vec![5, 1000, 1020],
+            },
+            first
+        );
+    }
+
+    #[test]
+    fn combinations_of_any_size_are_found() {
+        let entries = [1, 2, 3, 4, 5, u32::MAX];
+        assert_eq!(vec![vec![1, 4], vec![2, 3]], all_values(&entries, 2, 5, false));
+        assert_eq!(vec![vec![5]], all_values(&entries, 1, 5, false));
+        assert_eq!(vec![vec![1, 2, 3, 4]], all_values(&entries, 4, 10, false));
+        assert_eq!(vec![Vec::<u32>::new()], all_values(&entries, 0, 0, false));
+        assert!(all_values(&entries, 0, 1, false).is_empty());
+
+        // The sum of large entries does not overflow
+        let entries = [u32::MAX, u32::MAX, 3];
+        assert_eq!(vec![vec![u32::MAX, u32::MAX]], all_values(&entries, 2, 2 * u32::MAX as u64, true));
+        assert_eq!(vec![vec![3, u32::MAX]], all_values(&entries, 2, u32::MAX as u64 + 3, true));
+    }
+}
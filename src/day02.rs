@@ -0,0 +1,251 @@
+use std::fmt;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<PasswordEntry>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<PasswordEntry>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, row)| PasswordEntry::parse(row, idx))
+            .collect()
+    }
+
+    fn part1(entries: &Vec<PasswordEntry>) -> u32 {
+        count_valid_passwords(entries, &[&CountRange])[0]
+    }
+
+    fn part2(entries: &Vec<PasswordEntry>) -> u32 {
+        count_valid_passwords(entries, &[&PositionXor])[0]
+    }
+}
+
+/*
+ * A line of the password database: the two numbers and the letter of the policy, and
+ * the password. What the numbers mean depends on the policy.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordEntry {
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    /*
+     * Parses a line like "1-3 a: abcde". The letter can be any character, and the
+     * numbers have to be at least 1.
+     */
+    pub fn parse(row: &str, row_idx: usize) -> ParseResult<PasswordEntry> {
+        let (numbers, rest) = match row.find(' ') {
+            Some(pos) => (&row[..pos], &row[pos + 1..]),
+            None => return Err(ParseError::end_of_input(row_idx, row.chars().count(), "' '")),
+        };
+        let (first, second) = match numbers.find('-') {
+            Some(pos) => (&numbers[..pos], &numbers[pos + 1..]),
+            None => return Err(ParseError::new(row_idx, 0, "\"<number>-<number>\"", numbers)),
+        };
+        let first = PasswordEntry::parse_number(row, first, row_idx)?;
+        let second = PasswordEntry::parse_number(row, second, row_idx)?;
+
+        let mut chars = rest.chars();
+        let letter_col = column_of(row, rest);
+        let letter = chars
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(row_idx, letter_col, "a policy letter"))?;
+        let password = match chars.as_str().strip_prefix(": ") {
+            Some(password) => password,
+            None => return Err(ParseError::new(row_idx, letter_col + 1, "\": \"", chars.as_str())),
+        };
+        if password.is_empty() {
+            return Err(ParseError::end_of_input(row_idx, row.chars().count(), "a password"));
+        }
+
+        Ok(PasswordEntry {
+            first,
+            second,
+            letter,
+            password: password.to_string(),
+        })
+    }
+
+    fn parse_number(row: &str, token: &str, row_idx: usize) -> ParseResult<usize> {
+        let col = column_of(row, token);
+        match parse_token(token, row_idx, col, "a number")? {
+            0 => Err(ParseError::new(row_idx, col, "a number above 0", token)),
+            number => Ok(number),
+        }
+    }
+}
+
+/*
+ * The constraint of a policy that a password fails.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    TooFew { count: usize, min: usize },
+    TooMany { count: usize, max: usize },
+    NeitherPosition,
+    BothPositions,
+    PositionOutOfRange { position: usize, length: usize },
+    // For policies outside this module
+    Other(String),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::TooFew { count, min } => write!(f, "the letter appears {} times, below {}", count, min),
+            Violation::TooMany { count, max } => write!(f, "the letter appears {} times, above {}", count, max),
+            Violation::NeitherPosition => write!(f, "the letter is at neither position"),
+            Violation::BothPositions => write!(f, "the letter is at both positions"),
+            Violation::PositionOutOfRange { position, length } => {
+                write!(f, "position {} is past the end of the {} characters", position, length)
+            }
+            Violation::Other(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+pub type Verdict = Result<(), Violation>;
+
+pub trait PasswordPolicy {
+    fn check(&self, entry: &PasswordEntry) -> Verdict;
+}
+
+/*
+ * The policy of the sled rental place: the letter must appear between `first` and
+ * `second` times, inclusive.
+ */
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, entry: &PasswordEntry) -> Verdict {
+        let count = entry.password.chars().filter(|c| *c == entry.letter).count();
+        if count < entry.first {
+            Err(Violation::TooFew { count, min: entry.first })
+        } else if count > entry.second {
+            Err(Violation::TooMany { count, max: entry.second })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/*
+ * The policy of the Official Toboggan Corporate Authentication System: the letter must
+ * be at exactly one of the 1-based positions `first` and `second`.
+ */
+pub struct PositionXor;
+
+impl PasswordPolicy for PositionXor {
+    fn check(&self, entry: &PasswordEntry) -> Verdict {
+        let chars: Vec<char> = entry.password.chars().collect();
+        let has_letter_at = |position: usize| match position.checked_sub(1).and_then(|idx| chars.get(idx)) {
+            Some(c) => Ok(*c == entry.letter),
+            None => Err(Violation::PositionOutOfRange {
+                position,
+                length: chars.len(),
+            }),
+        };
+        match (has_letter_at(entry.first)?, has_letter_at(entry.second)?) {
+            (true, true) => Err(Violation::BothPositions),
+            (false, false) => Err(Violation::NeitherPosition),
+            _ => Ok(()),
+        }
+    }
+}
+
+/*
+ * Checks every entry against every policy in a single pass. The verdicts of a line are
+ * in the order of the policies.
+ */
+pub fn check_passwords(entries: &[PasswordEntry], policies: &[&dyn PasswordPolicy]) -> Vec<Vec<Verdict>> {
+    entries
+        .iter()
+        .map(|entry| policies.iter().map(|policy| policy.check(entry)).collect())
+        .collect()
+}
+
+// The number of valid passwords for every policy
+pub fn count_valid_passwords(entries: &[PasswordEntry], policies: &[&dyn PasswordPolicy]) -> Vec<u32> {
+    let mut counts = vec![0; policies.len()];
+    for verdicts in check_passwords(entries, policies) {
+        for (count, verdict) in counts.iter_mut().zip(verdicts) {
+            if verdict.is_ok() {
+                *count += 1;
+            }
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_passwords, CountRange, Day02, PasswordEntry, PositionXor, Violation};
+    use crate::Solution;
+
+    fn entry(first: usize, second: usize, letter: char, password: &str) -> PasswordEntry {
+        PasswordEntry {
+            first,
+            second,
+            letter,
+            password: password.to_string(),
+        }
+    }
+
+    #[test]
+    fn verdicts_explain_the_failed_constraint() {
+        let entries = vec![
+            entry(1, 3, 'a', "abcde"),
+            entry(1, 3, 'b', "cdefg"),
+            entry(2, 9, 'c', "ccccccccc"),
+            entry(1, 2, 'd', "ddd"),
+        ];
+        let verdicts = check_passwords(&entries, &[&CountRange, &PositionXor]);
+        assert_eq!(vec![Ok(()), Ok(())], verdicts[0]);
+        assert_eq!(
+            vec![Err(Violation::TooFew { count: 0, min: 1 }), Err(Violation::NeitherPosition)],
+            verdicts[1]
+        );
+        assert_eq!(vec![Ok(()), Err(Violation::BothPositions)], verdicts[2]);
+        assert_eq!(Err(Violation::TooMany { count: 3, max: 2 }), verdicts[3][0]);
+        assert_eq!(
+            "the letter appears 3 times, above 2",
+            verdicts[3][0].as_ref().unwrap_err().to_string()
+        );
+
+        // Positions are counted in characters, not bytes
+        let entries = vec![entry(2, 4, 'é', "aé€x"), entry(1, 5, 'a', "abc")];
+        let verdicts = check_passwords(&entries, &[&PositionXor]);
+        assert_eq!(Ok(()), verdicts[0][0]);
+        assert_eq!(Err(Violation::PositionOutOfRange { position: 5, length: 3 }), verdicts[1][0]);
+    }
+
+    #[test]
+    fn lines_are_parsed_into_entries() {
+        let input = vec!["1-3 a: abcde".to_string(), "10-12 ß: ßßx".to_string()];
+        let entries = Day02::parse(&input).unwrap();
+        assert_eq!(entry(1, 3, 'a', "abcde"), entries[0]);
+        assert_eq!(entry(10, 12, 'ß', "ßßx"), entries[1]);
+    }
+
+    #[test]
+    fn malformed_lines_are_errors() {
+        let error = |row: &str| Day02::parse(&[row.to_string()]).err().unwrap().to_string();
+        assert_eq!("line 1, column 1: expected \"<number>-<number>\", found \"13\"", error("13 a: abc"));
+        assert_eq!("line 1, column 3: expected a number, found \"x\"", error("1-x a: abc"));
+        assert_eq!("line 1, column 1: expected a number above 0, found \"0\"", error("0-3 a: abc"));
+        assert_eq!("line 1, column 6: expected \": \", found \" abc\"", error("1-3 a abc"));
+        assert_eq!("line 1, column 8: expected a password, found end of input", error("1-3 a: "));
+        assert_eq!("line 1, column 4: expected ' ', found end of input", error("1-3"));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::error::{ParseError, ParseResult};
+use crate::grid::Grid;
+use crate::number_theory::gcd;
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    // The map of the slope, true for the trees
+    type Input = Grid<bool>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Grid<bool>> {
+        Grid::parse(input, |c, row, col| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(ParseError::new(row, col, "'.' or '#'", &c.to_string())),
+        })
+    }
+
+    fn part1(map: &Grid<bool>) -> u32 {
+        let slope = Slope { right: 3, down: 1 };
+        analyze_slopes(map, slope.right, slope.down).trees(slope).unwrap()
+    }
+
+    fn part2(map: &Grid<bool>) -> u32 {
+        let analysis = analyze_slopes(map, 7, 2);
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(right, down)| analysis.trees(Slope { right, down }).unwrap())
+            .product()
+    }
+}
+
+/*
+ * A slope in lowest terms, e.g. 3 right for every 1 down.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+/*
+ * The number of trees encountered on every slope, in slope order.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlopeAnalysis {
+    pub tree_counts: Vec<(Slope, u32)>,
+}
+
+impl SlopeAnalysis {
+    pub fn trees(&self, slope: Slope) -> Option<u32> {
+        self.tree_counts
+            .binary_search_by_key(&slope, |(s, _)| *s)
+            .ok()
+            .map(|idx| self.tree_counts[idx].1)
+    }
+
+    // The slopes with the fewest trees, all of them if there is a tie
+    pub fn fewest_trees(&self) -> Vec<(Slope, u32)> {
+        self.with_trees(self.tree_counts.iter().map(|(_, trees)| *trees).min())
+    }
+
+    // The slopes with the most trees, all of them if there is a tie
+    pub fn most_trees(&self) -> Vec<(Slope, u32)> {
+        self.with_trees(self.tree_counts.iter().map(|(_, trees)| *trees).max())
+    }
+
+    fn with_trees(&self, count: Option<u32>) -> Vec<(Slope, u32)> {
+        self.tree_counts.iter().copied().filter(|(_, trees)| Some(*trees) == count).collect()
+    }
+}
+
+/*
+ * Counts the trees on every slope that goes at most `max_right` right and `max_down`
+ * down per step, from the top left corner to the bottom. Slopes that are not in lowest
+ * terms follow the same line as their reduced form, so only the reduced ones are
+ * counted. The map is swept once, row by row, for all slopes at the same time.
+ */
+pub fn analyze_slopes(map: &Grid<bool>, max_right: usize, max_down: usize) -> SlopeAnalysis {
+    let mut tree_counts: Vec<(Slope, u32)> = (0..=max_right)
+        .flat_map(|right| (1..=max_down).map(move |down| Slope { right, down }))
+        .filter(|slope| gcd(slope.right as i64, slope.down as i64) == 1)
+        .map(|slope| (slope, 0))
+        .collect();
+
+    for y in 0..map.height() {
+        for (slope, trees) in tree_counts.iter_mut().filter(|(slope, _)| y % slope.down == 0) {
+            // The map repeats to the right
+            let x = y / slope.down * slope.right;
+            if *map.get_wrapping(x as isize, y as isize) {
+                *trees += 1;
+            }
+        }
+    }
+
+    SlopeAnalysis { tree_counts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{analyze_slopes, Day03, Slope};
+    use crate::Solution;
+
+    #[test]
+    fn all_slopes_are_counted_in_one_sweep() {
+        let input: Vec<String> = [
+            "..##.......",
+            "#...#...#..",
+            ".#....#..#.",
+            "..#.#...#.#",
+            ".#...##..#.",
+            "..#.##.....",
+            ".#.#.#....#",
+            ".#........#",
+            "#.##...#...",
+            "#...##....#",
+            ".#..#...#.#",
+        ]
+        .iter()
+        .map(|row| row.to_string())
+        .collect();
+        let map = Day03::parse(&input).unwrap();
+
+        let analysis = analyze_slopes(&map, 7, 2);
+        // 0 to 7 right for 1 down, and the odd ones for 2 down
+        assert_eq!(12, analysis.tree_counts.len());
+        assert_eq!(Some(7), analysis.trees(Slope { right: 3, down: 1 }));
+        assert_eq!(Some(2), analysis.trees(Slope { right: 1, down: 2 }));
+        assert_eq!(None, analysis.trees(Slope { right: 2, down: 2 }));
+        assert_eq!(vec![(Slope { right: 3, down: 1 }, 7)], analysis.most_trees());
+        assert_eq!(vec![(Slope { right: 5, down: 2 }, 0)], analysis.fewest_trees());
+
+        // Ties are all returned
+        let analysis = analyze_slopes(&map, 1, 2);
+        assert_eq!(
+            vec![(Slope { right: 1, down: 1 }, 2), (Slope { right: 1, down: 2 }, 2)],
+            analysis.fewest_trees()
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::error::{ParseError, ParseResult};
+use crate::helpers::records::sections;
+use crate::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Passport>> {
+        parse_passports(input)
+    }
+
+    fn part1(input: &Vec<Passport>) -> u32 {
+        // Check that all the fields are present (except cid)
+        input.iter().filter(|pp| pp.has_mandatory_fields()).count() as u32
+    }
+
+    fn part2(input: &Vec<Passport>) -> u32 {
+        // Check that all the fields are valid
+        input.iter().filter(|pp| pp.is_valid()).count() as u32
+    }
+}
+
+fn parse_passports(input: &[String]) -> ParseResult<Vec<Passport>> {
+    let mut passports = Vec::new();
+    let re = Regex::new(r"^(\w+):(\S+)$").unwrap();
+
+    for section in sections(input) {
+        let mut passport = Passport::new();
+        for (row_idx, col, field) in section.records() {
+            let cap = re
+                .captures(field)
+                .ok_or_else(|| ParseError::new(row_idx, col, "\"<key>:<value>\"", field))?;
+            let value = Some(cap[2].to_string());
+            match &cap[1] {
+                "byr" => passport.byr = value,
+                "iyr" => passport.iyr = value,
+                "eyr" => passport.eyr = value,
+                "hgt" => passport.hgt = value,
+                "hcl" => passport.hcl = value,
+                "ecl" => passport.ecl = value,
+                "pid" => passport.pid = value,
+                "cid" => passport.cid = value,
+                &_ => {}
+            }
+        }
+        passports.push(passport);
+    }
+
+    Ok(passports)
+}
+
+pub struct Passport {
+    byr: Option<String>,
+    iyr: Option<String>,
+    eyr: Option<String>,
+    hgt: Option<String>,
+    hcl: Option<String>,
+    ecl: Option<String>,
+    pid: Option<String>,
+    cid: Option<String>,
+}
+
+lazy_static! {
+    static ref HEIGHT_RE: Regex = Regex::new(r"(\d+)(\w\w)").unwrap();
+    static ref HAIR_COLOR_RE: Regex = Regex::new(r"#[0-9a-f]{6}").unwrap();
+    static ref PASSPORT_ID_RE: Regex = Regex::new(r"^\d{9}$").unwrap();
+}
+
+impl Passport {
+
+    fn new() -> Passport {
+        Passport {
+            byr: None,
+            iyr: None,
+            eyr: None,
+            hgt: None,
+            hcl: None,
+            ecl: None,
+            pid: None,
+            cid: None,
+        }
+    }
+
+    /*
+     * Check that the passport has all the mandatory fields (i.e. all fields except `cid`).
+     */
+    fn has_mandatory_fields(&self) -> bool {
+        self.byr.is_some()
+            && self.iyr.is_some()
+            && self.eyr.is_some()
+            && self.hgt.is_some()
+            && self.hcl.is_some()
+            && self.ecl.is_some()
+            && self.pid.is_some()
+    }
+
+    /*
+     * Check that the passport has all the mandatory fields (i.e. all fields except `cid`)
+     * and the values are valid.
+     */
+    fn is_valid(&self) -> bool {
+        if !self.has_mandatory_fields() {
+            return false;
+        }
+
+        match self.byr.as_ref().unwrap().parse::<u32>() {
+            Ok(_byr @ 1920..=2002) => {} // No-op, field is valid
+            _ => {
+                return false;
+            }
+        }
+        match self.iyr.as_ref().unwrap().parse::<u32>() {
+            Ok(_iyr @ 2010..=2020) => {} // No-op, field is valid
+            _ => {
+                return false;
+            }
+        }
+        match self.eyr.as_ref().unwrap().parse::<u32>() {
+            Ok(_eyr @ 2020..=2030) => {} // No-op, field is valid
+            _ => {
+                return false;
+            }
+        }
+
+        match HEIGHT_RE.captures(self.hgt.as_ref().unwrap()) {
+            Some(height_caps) => match height_caps[1].parse::<u32>() {
+                Ok(height) => match &height_caps[2] {
+                    "cm" if (150..=193).contains(&height) => {}
+                    "in" if (59..=76).contains(&height) => {}
+                    _ => {
+                        return false;
+                    }
+                },
+                _ => {
+                    return false;
+                }
+            },
+            None => {
+                return false;
+            }
+        }
+
+        if !HAIR_COLOR_RE.is_match(self.hcl.as_ref().unwrap()) {
+            return false;
+        }
+
+        let valid_eye_colors = [
+            "amb".to_string(),
+            "blu".to_string(),
+            "brn".to_string(),
+            "gry".to_string(),
+            "grn".to_string(),
+            "hzl".to_string(),
+            "oth".to_string(),
+        ];
+        if !valid_eye_colors.contains(self.ecl.as_ref().unwrap()) {
+            return false;
+        }
+
+        if !PASSPORT_ID_RE.is_match(self.pid.as_ref().unwrap()) {
+            return false;
+        }
+
+        true
+    }
+}
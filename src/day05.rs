@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+
+use crate::error::{ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<u16>;
+    type Output1 = Option<u16>;
+    type Output2 = Option<u16>;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<u16>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| parse_seat_id(r, idx))
+            .collect()
+    }
+
+    fn part1(input: &Vec<u16>) -> Option<u16> {
+        input.iter().max().copied()
+    }
+
+    fn part2(input: &Vec<u16>) -> Option<u16> {
+        let testing_range = *input.iter().min()?..=*input.iter().max()?;
+        let seat_id_set: BTreeSet<u16> = input.iter().copied().collect();
+
+        testing_range.into_iter().find(|potential_seat_id| {
+            !seat_id_set.contains(potential_seat_id)
+                && seat_id_set.contains(&(potential_seat_id - 1))
+                && seat_id_set.contains(&(potential_seat_id + 1))
+        })
+    }
+}
+
+fn parse_seat_id(seat_def: &str, row: usize) -> ParseResult<u16> {
+    // Construct a number from the whole definition, and extract row and column with bitwise operations
+    let mut numeric_def: u16 = 0;
+    let mut chars = seat_def.chars();
+    for col in 0..10 {
+        let bit = match (col, chars.next()) {
+            (0..=6, Some('F')) | (7..=9, Some('L')) => 0,
+            (0..=6, Some('B')) | (7..=9, Some('R')) => 1,
+            (0..=6, found) => return Err(seat_def_error(row, col, "'F' or 'B'", found)),
+            (_, found) => return Err(seat_def_error(row, col, "'L' or 'R'", found)),
+        };
+        numeric_def = (numeric_def << 1) | bit;
+    }
+    if let Some(c) = chars.next() {
+        return Err(ParseError::new(row, 10, "end of the seat definition", &c.to_string()));
+    }
+
+    Ok(numeric_def)
+}
+
+fn seat_def_error(row: usize, col: usize, expected: &str, found: Option<char>) -> ParseError {
+    match found {
+        Some(c) => ParseError::new(row, col, expected, &c.to_string()),
+        None => ParseError::end_of_input(row, col, expected),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn test_parse_seat_id() {
+        assert_eq!(Ok(567), super::parse_seat_id("BFFFBBFRRR", 0));
+        assert_eq!(Ok(119), super::parse_seat_id("FFFBBBFRRR", 0));
+        assert_eq!(Ok(820), super::parse_seat_id("BBFFBBFRLL", 0));
+    }
+
+    #[test]
+    fn test_parse_seat_id_errors() {
+        let error = super::parse_seat_id("BFFFBBFRXR", 3).unwrap_err();
+        assert_eq!((4, 9), (error.line, error.column));
+        assert_eq!("X", error.found);
+
+        let error = super::parse_seat_id("BFFF", 0).unwrap_err();
+        assert_eq!("line 1, column 5: expected 'F' or 'B', found end of input", error.to_string());
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+
+use crate::error::{ParseError, ParseResult};
+use crate::helpers::records::sections;
+use crate::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // The answers of every passenger, by group
+    type Input = Vec<Vec<BTreeSet<char>>>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Vec<BTreeSet<char>>>> {
+        sections(input)
+            .map(|group| group.lines().map(|(row_idx, row)| parse_answers(row, row_idx)).collect())
+            .collect()
+    }
+
+    fn part1(groups: &Vec<Vec<BTreeSet<char>>>) -> u32 {
+        count_answers_of_anyone(groups)
+    }
+
+    fn part2(groups: &Vec<Vec<BTreeSet<char>>>) -> u32 {
+        count_answers_of_everyone(groups)
+    }
+}
+
+fn parse_answers(row: &str, row_idx: usize) -> ParseResult<BTreeSet<char>> {
+    row.chars()
+        .enumerate()
+        .map(|(col, c)| match c {
+            'a'..='z' => Ok(c),
+            _ => Err(ParseError::new(row_idx, col, "a question from 'a' to 'z'", &c.to_string())),
+        })
+        .collect()
+}
+
+fn count_answers_of_anyone(groups: &[Vec<BTreeSet<char>>]) -> u32 {
+    groups
+        .iter()
+        .map(|group| group.iter().flatten().collect::<BTreeSet<&char>>().len() as u32)
+        .sum()
+}
+
+fn count_answers_of_everyone(groups: &[Vec<BTreeSet<char>>]) -> u32 {
+    groups
+        .iter()
+        .map(|group| {
+            // Only the answers present on all rows are left in the intersection
+            let mut passengers = group.iter();
+            let first = passengers.next().cloned().unwrap_or_default();
+            passengers.fold(first, |shared, answers| &shared & answers).len() as u32
+        })
+        .sum()
+}
@@ -0,0 +1,272 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+lazy_static! {
+    static ref BAG_DEF_RE: Regex = Regex::new(r"^(.*?) bags contain (.*)$").unwrap();
+    static ref INCLUDED_BAG_RE: Regex = Regex::new(r"\s*(\d+)\s+(.*?)\s+bags?.?").unwrap();
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = BagGraph;
+    type Output1 = usize;
+    type Output2 = Option<u64>;
+
+    fn parse(input: &[String]) -> ParseResult<BagGraph> {
+        parse_bag_graph(input)
+    }
+
+    fn part1(bag_graph: &BagGraph) -> usize {
+        match bag_graph.id("shiny gold") {
+            Some(id) => bag_graph.containing_bags(id).len(),
+            None => 0,
+        }
+    }
+
+    fn part2(bag_graph: &BagGraph) -> Option<u64> {
+        bag_graph.count_nested_bags(bag_graph.id("shiny gold")?)
+    }
+}
+
+/*
+ * The interned ID of a bag color, an index into the lists of the graph.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BagId(usize);
+
+/*
+ * The bag rules as a directed graph from every bag to the bags it directly contains.
+ */
+#[derive(Debug, Default)]
+pub struct BagGraph {
+    colors: Vec<String>,
+    ids: HashMap<String, BagId>,
+    // The directly contained bags and their counts, by container
+    contents: Vec<Vec<(BagId, u64)>>,
+    // The bags that directly contain a bag
+    containers: Vec<Vec<BagId>>,
+}
+
+impl BagGraph {
+    pub fn new() -> BagGraph {
+        BagGraph::default()
+    }
+
+    /*
+     * Returns the ID of the color, adding it to the graph if it is new.
+     */
+    pub fn intern(&mut self, color: &str) -> BagId {
+        if let Some(id) = self.ids.get(color) {
+            return *id;
+        }
+        let id = BagId(self.colors.len());
+        self.colors.push(color.to_string());
+        self.ids.insert(color.to_string(), id);
+        self.contents.push(Vec::new());
+        self.containers.push(Vec::new());
+        id
+    }
+
+    pub fn add_contents(&mut self, container: BagId, bag: BagId, count: u64) {
+        self.contents[container.0].push((bag, count));
+        if !self.containers[bag.0].contains(&container) {
+            self.containers[bag.0].push(container);
+        }
+    }
+
+    pub fn id(&self, color: &str) -> Option<BagId> {
+        self.ids.get(color).copied()
+    }
+
+    pub fn color(&self, id: BagId) -> &str {
+        &self.colors[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    pub fn contents(&self, id: BagId) -> &[(BagId, u64)] {
+        &self.contents[id.0]
+    }
+
+    pub fn containers(&self, id: BagId) -> &[BagId] {
+        &self.containers[id.0]
+    }
+
+    /*
+     * Returns the bags that contain the bag, directly or further up.
+     */
+    pub fn containing_bags(&self, id: BagId) -> HashSet<BagId> {
+        let mut containing: HashSet<BagId> = HashSet::new();
+        let mut stack = vec![id];
+        while let Some(top) = stack.pop() {
+            for container in self.containers(top) {
+                if containing.insert(*container) {
+                    stack.push(*container);
+                }
+            }
+        }
+        containing
+    }
+
+    /*
+     * Returns the number of bags inside the bag, or None if the bag contains itself or
+     * the count does not fit in a u64. The count of every bag on the way is computed
+     * only once.
+     */
+    pub fn count_nested_bags(&self, id: BagId) -> Option<u64> {
+        let mut memo = vec![Visit::New; self.len()];
+        self.count_nested_bags_memoized(id, &mut memo)
+    }
+
+    fn count_nested_bags_memoized(&self, id: BagId, memo: &mut Vec<Visit>) -> Option<u64> {
+        match memo[id.0] {
+            Visit::Done(count) => return count,
+            // The bag is being counted further down the stack, so it contains itself
+            Visit::InProgress => return None,
+            Visit::New => {}
+        }
+        memo[id.0] = Visit::InProgress;
+        let mut total: Option<u64> = Some(0);
+        for (bag, count) in self.contents(id) {
+            let nested = self.count_nested_bags_memoized(*bag, memo);
+            // The contained bags themselves and everything inside them
+            total = total
+                .zip(nested.and_then(|n| n.checked_add(1)))
+                .and_then(|(total, per_bag)| total.checked_add(per_bag.checked_mul(*count)?));
+        }
+        memo[id.0] = Visit::Done(total);
+        total
+    }
+
+    /*
+     * Returns a cycle of bags that contain each other, starting and ending at the same
+     * bag, or None if there is none.
+     */
+    pub fn find_cycle(&self) -> Option<Vec<BagId>> {
+        let mut visits = vec![Visit::New; self.len()];
+        let mut path = Vec::new();
+        (0..self.len()).find_map(|start| self.find_cycle_from(BagId(start), &mut visits, &mut path))
+    }
+
+    fn find_cycle_from(&self, id: BagId, visits: &mut Vec<Visit>, path: &mut Vec<BagId>) -> Option<Vec<BagId>> {
+        match visits[id.0] {
+            Visit::Done(_) => return None,
+            Visit::InProgress => {
+                let start = path.iter().position(|bag| *bag == id).unwrap();
+                let mut cycle = path[start..].to_vec();
+                cycle.push(id);
+                return Some(cycle);
+            }
+            Visit::New => {}
+        }
+        visits[id.0] = Visit::InProgress;
+        path.push(id);
+        for (bag, _) in self.contents(id) {
+            if let Some(cycle) = self.find_cycle_from(*bag, visits, path) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        visits[id.0] = Visit::Done(None);
+        None
+    }
+}
+
+// The state of a bag in a depth-first search
+#[derive(Debug, Clone, Copy)]
+enum Visit {
+    New,
+    InProgress,
+    Done(Option<u64>),
+}
+
+fn parse_bag_graph(input: &[String]) -> ParseResult<BagGraph> {
+    let mut bag_graph = BagGraph::new();
+
+    for (row_idx, bag_def) in input.iter().enumerate() {
+        let bag_captures = BAG_DEF_RE.captures(bag_def).ok_or_else(|| {
+            ParseError::new(row_idx, 0, "\"<color> bags contain <contents>\"", bag_def)
+        })?;
+        let bag_id = bag_graph.intern(bag_captures[1].trim());
+        let inc_bags = bag_captures.get(2).unwrap().as_str();
+        if inc_bags == "no other bags." {
+            continue;
+        }
+
+        for inc_bag_def in inc_bags.split(',') {
+            let inc_bag_captures = INCLUDED_BAG_RE.captures(inc_bag_def).ok_or_else(|| {
+                ParseError::new(
+                    row_idx,
+                    column_of(bag_def, inc_bag_def),
+                    "\"<count> <color> bag(s)\"",
+                    inc_bag_def.trim(),
+                )
+            })?;
+            let inc_bag_id = bag_graph.intern(inc_bag_captures[2].trim());
+            let count = inc_bag_captures.get(1).unwrap().as_str();
+            let inc_bag_count = parse_token(count, row_idx, column_of(bag_def, count), "a bag count")?;
+
+            bag_graph.add_contents(bag_id, inc_bag_id, inc_bag_count);
+        }
+    }
+
+    Ok(bag_graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day07;
+    use crate::Solution;
+
+    fn rules(rows: &[&str]) -> Vec<String> {
+        rows.iter().map(|r| r.to_string()).collect()
+    }
+
+    #[test]
+    fn cycles_are_detected() {
+        let input = rules(&[
+            "shiny gold bags contain 2 dark red bags.",
+            "dark red bags contain 1 dark blue bag, 3 pale olive bags.",
+            "dark blue bags contain 4 shiny gold bags.",
+            "pale olive bags contain no other bags.",
+        ]);
+        let bag_graph = Day07::parse(&input).unwrap();
+        let cycle: Vec<&str> = bag_graph.find_cycle().unwrap().into_iter().map(|id| bag_graph.color(id)).collect();
+        assert_eq!(vec!["shiny gold", "dark red", "dark blue", "shiny gold"], cycle);
+        assert_eq!(None, Day07::part2(&bag_graph));
+        // Every bag of the cycle contains shiny gold bags, including themselves
+        assert_eq!(3, Day07::part1(&bag_graph));
+    }
+
+    // Shiny gold bags with 200 bags inside, which have 200 bags inside and so on
+    fn nested_levels(levels: u32) -> Vec<String> {
+        let mut input: Vec<String> = (1..levels)
+            .map(|level| format!("level{} bags contain 200 level{} bags.", level, level + 1))
+            .collect();
+        input.push("shiny gold bags contain 200 level1 bags.".to_string());
+        input.push(format!("level{} bags contain no other bags.", levels));
+        input
+    }
+
+    #[test]
+    fn counts_do_not_overflow() {
+        let bag_graph = Day07::parse(&nested_levels(6)).unwrap();
+        assert_eq!(None, bag_graph.find_cycle());
+        assert_eq!(Some((1..=6).map(|n| 200u64.pow(n)).sum()), Day07::part2(&bag_graph));
+
+        // 200^9 bags do not fit in a u64
+        let bag_graph = Day07::parse(&nested_levels(9)).unwrap();
+        assert_eq!(None, Day07::part2(&bag_graph));
+    }
+}
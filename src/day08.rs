@@ -0,0 +1,69 @@
+use crate::error::ParseResult;
+use crate::vm::{Instruction, Machine, Stop, Termination};
+use crate::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+    type Output1 = Option<i32>;
+    type Output2 = Option<i32>;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Instruction>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| Instruction::parse(r, idx))
+            .collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Option<i32> {
+        let mut machine = Machine::new(instructions.to_vec());
+        match machine.run(&Termination::default()) {
+            Stop::Loop => Some(machine.accumulator()),
+            _ => None,
+        }
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Option<i32> {
+        for i in 0..instructions.len() {
+            if let Instruction::Acc(_) = instructions[i] {
+                // Acc instructions are left untouched, no need to evaluate
+                continue;
+            }
+            // Swap the i'th instruction if it's a Nop or Jmp, and evaluate the program
+            let mut instructions_copy: Vec<Instruction> = instructions.to_vec();
+            let new_instruction = match instructions[i] {
+                Instruction::Acc(arg) => Instruction::Acc(arg),
+                Instruction::Jmp(arg) => Instruction::Nop(arg),
+                Instruction::Nop(arg) => Instruction::Jmp(arg),
+            };
+            instructions_copy[i] = new_instruction;
+
+            let mut machine = Machine::new(instructions_copy);
+            if machine.run(&Termination::default()) == Stop::Halted {
+                return Some(machine.accumulator());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day08;
+    use crate::Solution;
+
+    #[test]
+    fn parse_errors_report_position() {
+        let input = vec!["nop +0".to_string(), "acc x1".to_string()];
+        let error = Day08::parse(&input).err().unwrap();
+        assert_eq!((2, 5), (error.line, error.column));
+
+        let input = vec!["mul +2".to_string()];
+        assert_eq!(
+            "line 1, column 1: expected \"acc\", \"jmp\" or \"nop\", found \"mul\"",
+            Day08::parse(&input).err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day09;
+
+// The puzzle uses a preamble of 25 numbers, the example only 5
+const DEFAULT_PREAMBLE: usize = 25;
+const PREAMBLE_PREFIX: &str = "preamble: ";
+
+impl Solution for Day09 {
+    type Input = Xmas;
+    type Output1 = Option<i64>;
+    type Output2 = Option<i64>;
+
+    /*
+     * Parses one number per row. The first row can set the preamble length, like
+     * "preamble: 5", for inputs that do not use the default one.
+     */
+    fn parse(input: &[String]) -> ParseResult<Xmas> {
+        let (preamble, first_row) = match input.first().and_then(|r| r.strip_prefix(PREAMBLE_PREFIX)) {
+            Some(length) => (parse_token(length, 0, column_of(&input[0], length), "a preamble length")?, 1),
+            None => (DEFAULT_PREAMBLE, 0),
+        };
+        let numbers: Vec<i64> = input
+            .iter()
+            .enumerate()
+            .skip(first_row)
+            .map(|(idx, r)| parse_token(r, idx, 0, "a number"))
+            .collect::<ParseResult<_>>()?;
+        if numbers.len() <= preamble {
+            return Err(ParseError::end_of_input(
+                input.len(),
+                0,
+                &format!("a number after the preamble of {}", preamble),
+            ));
+        }
+
+        Ok(Xmas { preamble, numbers })
+    }
+
+    fn part1(xmas: &Xmas) -> Option<i64> {
+        task_1_internal(&xmas.numbers, xmas.preamble)
+    }
+
+    fn part2(xmas: &Xmas) -> Option<i64> {
+        task_2_internal(&xmas.numbers, xmas.preamble)
+    }
+}
+
+/*
+ * The XMAS encoded numbers, where every number after the preamble should be the sum of
+ * two of the `preamble` numbers before it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xmas {
+    pub preamble: usize,
+    pub numbers: Vec<i64>,
+}
+
+/*
+ * Returns the first number after the preamble that is not a sum of two of the numbers
+ * before it, or None if every number is valid.
+ */
+fn task_1_internal(rows: &[i64], preamble_size: usize) -> Option<i64> {
+    // Collect the possible value to a queue so that the first items are the input's first
+    // value added with the other preamble values, then the second value added with the
+    // other preamble values, etc.
+    // This way the "oldest" valid values can be dropped and new ones can be inserted on
+    // every item. Sums that overflow cannot match any number and are stored as None
+    let mut valid_values: VecDeque<Option<i64>> = VecDeque::new();
+    // Populate the collection of valid values with the initial preamble
+    for i in 0..preamble_size {
+        for j in 0..preamble_size {
+            if i != j {
+                valid_values.push_front(rows[i].checked_add(rows[j]));
+            }
+        }
+    }
+
+    for i in preamble_size..rows.len() {
+        if !valid_values.contains(&Some(rows[i])) {
+            return Some(rows[i]);
+        }
+
+        for j in (i - preamble_size + 1)..=i {
+            valid_values.pop_back();
+            valid_values.push_front(rows[i].checked_add(rows[j]));
+        }
+    }
+
+    None
+}
+
+/*
+ * Solve the task by maintaining the sum of consecutive numbers, extending the range from
+ * the front if the result is smaller than the target, and subtracting it from the back if
+ * the result is larger than the target. The range has at least two numbers. Returns None
+ * if there is no invalid number or no range adds up to it.
+ *
+ * Note: This only works because the input has no negative numbers.
+ */
+fn task_2_internal(rows: &[i64], preamble_size: usize) -> Option<i64> {
+    let invalid_number = task_1_internal(rows, preamble_size)?;
+
+    let mut range_start_incl: usize = 0;
+    let mut range_end_incl: usize = 0;
+    let mut current_sum: i64 = *rows.first()?;
+
+    loop {
+        let range_len = range_end_incl - range_start_incl + 1;
+        if current_sum == invalid_number && range_len >= 2 {
+            let slice = &rows[range_start_incl..=range_end_incl];
+            return slice.iter().min()?.checked_add(*slice.iter().max()?);
+        } else if current_sum < invalid_number || range_len < 2 {
+            range_end_incl += 1;
+            current_sum = current_sum.checked_add(*rows.get(range_end_incl)?)?;
+        } else {
+            current_sum -= rows[range_start_incl];
+            range_start_incl += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day09, Xmas};
+    use crate::Solution;
+
+    fn rows(input: &[&str]) -> Vec<String> {
+        input.iter().map(|r| r.to_string()).collect()
+    }
+
+    #[test]
+    fn the_preamble_length_can_be_given() {
+        let xmas = Day09::parse(&rows(&["preamble: 2", "1", "2", "3", "7"])).unwrap();
+        assert_eq!(
+            Xmas {
+                preamble: 2,
+                numbers: vec![1, 2, 3, 7]
+            },
+            xmas
+        );
+        assert_eq!(Some(7), Day09::part1(&xmas));
+        assert_eq!(25, Day09::parse(&rows(&["1"; 26])).unwrap().preamble);
+
+        let error = Day09::parse(&rows(&["preamble: 2", "1", "2"])).unwrap_err();
+        assert_eq!(
+            "line 4, column 1: expected a number after the preamble of 2, found end of input",
+            error.to_string()
+        );
+        let error = Day09::parse(&rows(&["preamble: x"])).unwrap_err();
+        assert_eq!("line 1, column 11: expected a preamble length, found \"x\"", error.to_string());
+    }
+
+    #[test]
+    fn missing_numbers_and_ranges_are_unsolved() {
+        // Every number is valid
+        let xmas = Day09::parse(&rows(&["preamble: 2", "1", "2", "3"])).unwrap();
+        assert_eq!(None, Day09::part1(&xmas));
+        assert_eq!(None, Day09::part2(&xmas));
+        // 7 is invalid, but no range of two or more numbers adds up to it
+        let xmas = Day09::parse(&rows(&["preamble: 2", "1", "2", "3", "7"])).unwrap();
+        assert_eq!(None, Day09::part2(&xmas));
+    }
+}
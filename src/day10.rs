@@ -0,0 +1,108 @@
+use crate::error::{parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+    type Output1 = u32;
+    type Output2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<u32>> {
+        get_all_joltages(input)
+    }
+
+    fn part1(rows: &Vec<u32>) -> u32 {
+        // Solution idea: calculate differences between consecutive elements, count 1s and 3s
+        let orig = rows.iter();
+        let skipped = rows.iter().skip(1);
+
+        let mut counters = [0, 0, 0, 0];
+
+        orig.zip(skipped)
+            .map(|(&prev, &next)| (next - prev) as usize)
+            .for_each(|diff| counters[diff] += 1);
+
+        counters[1] * counters[3]
+    }
+
+    fn part2(rows: &Vec<u32>) -> u64 {
+        // Create a memoization array to reduce the recursion's runtime
+        let mut memo: Vec<Option<u64>> = vec![None; rows.len()];
+
+        count_paths_rec(rows, &mut memo, 0)
+    }
+}
+
+fn get_all_joltages(input: &[String]) -> ParseResult<Vec<u32>> {
+    let mut rows: Vec<u32> = input
+        .iter()
+        .enumerate()
+        .map(|(idx, r)| parse_token(r, idx, 0, "an adapter joltage"))
+        .collect::<ParseResult<_>>()?;
+
+    // The input is 0 jolts
+    rows.push(0);
+    let adapter_jolts = rows.iter().clone().fold(0, |acc, &x| acc.max(x)) + 3;
+    // Output is largest adapter joltage + 3
+    rows.push(adapter_jolts);
+
+    // Sort the joltages before returning so analysis is easier
+    rows.sort();
+
+    // Every adapter has to be reachable from the one below it
+    if let Some(pair) = rows.windows(2).find(|pair| pair[1] - pair[0] > 3) {
+        let row_idx = input.iter().position(|r| r.parse() == Ok(pair[1])).unwrap();
+        return Err(ParseError::new(
+            row_idx,
+            0,
+            &format!("an adapter at most 3 jolts above {}", pair[0]),
+            &input[row_idx],
+        ));
+    }
+
+    Ok(rows)
+}
+
+fn count_paths_rec(input: &[u32], memo: &mut [Option<u64>], from_idx: usize) -> u64 {
+    if memo[from_idx].is_none() {
+        if from_idx == input.len() - 1 {
+            memo[from_idx] = Some(1);
+        } else {
+            let mut subpath_count = 0;
+            let this_val = input.get(from_idx).unwrap();
+            for i in 1..=3 {
+                let next_idx = from_idx + i;
+                let next_val = input.get(next_idx);
+                if next_val.is_some() && *next_val.unwrap() <= this_val + 3 {
+                    subpath_count += count_paths_rec(input, memo, next_idx)
+                }
+            }
+            memo[from_idx] = Some(subpath_count);
+        }
+    }
+
+    memo[from_idx].unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use crate::Solution;
+
+    #[test]
+    fn gaps_above_3_jolts_are_errors() {
+        let input = vec!["1".to_string(), "10".to_string()];
+        let error = Day10::parse(&input).unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected an adapter at most 3 jolts above 1, found \"10\"",
+            error.to_string()
+        );
+        let input = vec!["5".to_string()];
+        let error = Day10::parse(&input).unwrap_err();
+        assert_eq!(
+            "line 1, column 1: expected an adapter at most 3 jolts above 0, found \"5\"",
+            error.to_string()
+        );
+    }
+}
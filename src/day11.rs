@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+use crate::grid::{Grid, DIRECTIONS8};
+use crate::visualization::{Color, Frame, FrameSink, NoFrames};
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<CellState>;
+    type Output1 = Option<u32>;
+    type Output2 = Option<u32>;
+
+    fn parse(input: &[String]) -> ParseResult<Grid<CellState>> {
+        Grid::parse(input, CellState::new)
+    }
+
+    fn part1(state: &Grid<CellState>) -> Option<u32> {
+        get_occupied_seats_in_end_state(state, &get_next_cell_state_v1, &mut NoFrames)
+    }
+
+    fn part2(state: &Grid<CellState>) -> Option<u32> {
+        get_occupied_seats_in_end_state(state, &get_next_cell_state_v2, &mut NoFrames)
+    }
+
+    fn visualize(state: &Grid<CellState>, part: u8, sink: &mut dyn FrameSink) -> bool {
+        match part {
+            1 => get_occupied_seats_in_end_state(state, &get_next_cell_state_v1, sink),
+            _ => get_occupied_seats_in_end_state(state, &get_next_cell_state_v2, sink),
+        };
+        true
+    }
+}
+
+// Returns the next state of the cell at the position
+type Evaluator = dyn Fn(&Grid<CellState>, (usize, usize)) -> CellState;
+
+fn get_occupied_seats_in_end_state(
+    init_state: &Grid<CellState>,
+    evaluator: &Evaluator,
+    sink: &mut dyn FrameSink,
+) -> Option<u32> {
+    let mut state = init_state.clone();
+    if sink.enabled() {
+        sink.emit(state_to_frame(&state, "Round 0"));
+    }
+
+    let max_iteration_count = 1000;
+    for round in 1..=max_iteration_count {
+        let prev_state = state;
+        state = timestep(&prev_state, evaluator);
+        if sink.enabled() {
+            sink.emit(state_to_frame(&state, &format!("Round {}", round)));
+        }
+        if prev_state == state {
+            return Some(state.values().filter(|c| **c == CellState::Occupied).count() as u32);
+        }
+    }
+    None
+}
+
+fn timestep(
+    prev_state: &Grid<CellState>,
+    evaluator: &Evaluator,
+) -> Grid<CellState> {
+    Grid::from_fn(prev_state.width(), prev_state.height(), |pos| evaluator(prev_state, pos))
+}
+
+fn get_next_cell_state_v1(state: &Grid<CellState>, pos: (usize, usize)) -> CellState {
+    let surrounding_occupied = DIRECTIONS8
+        .iter()
+        .filter(|dir| get_visible_cell_in_direction(state, pos, **dir, false) == Some(CellState::Occupied))
+        .count();
+    match state[pos] {
+        CellState::Empty if surrounding_occupied == 0 => CellState::Occupied,
+        CellState::Occupied if surrounding_occupied >= 4 => CellState::Empty,
+        cell => cell,
+    }
+}
+
+fn get_next_cell_state_v2(state: &Grid<CellState>, pos: (usize, usize)) -> CellState {
+    let surrounding_occupied = DIRECTIONS8
+        .iter()
+        .filter(|dir| get_visible_cell_in_direction(state, pos, **dir, true) == Some(CellState::Occupied))
+        .count();
+    match state[pos] {
+        CellState::Empty if surrounding_occupied == 0 => CellState::Occupied,
+        CellState::Occupied if surrounding_occupied >= 5 => CellState::Empty,
+        cell => cell,
+    }
+}
+
+/*
+ * Returns the state of the next cell visible in the provided direction, or None if no
+ * cell is visible in that direction. If `ignore_floor` is true, CellState::Floor will
+ * never be returned, and instead the first non-floor cell in that direction (or None) is
+ * returned.
+ */
+fn get_visible_cell_in_direction(
+    state: &Grid<CellState>,
+    pos: (usize, usize),
+    direction: (isize, isize),
+    ignore_floor: bool,
+) -> Option<CellState> {
+    let mut visible_cells = state.ray(pos, direction).map(|p| state[p]);
+    if ignore_floor {
+        visible_cells.find(|c| *c != CellState::Floor)
+    } else {
+        visible_cells.next()
+    }
+}
+
+fn state_to_frame(state: &Grid<CellState>, caption: &str) -> Frame {
+    let mut frame = Frame::new(caption, state.width(), state.height(), Color::rgb(40, 40, 40));
+    for ((x, y), cell) in state.iter() {
+        match cell {
+            CellState::Empty => frame.set(x, y, Color::rgb(60, 160, 60)),
+            CellState::Occupied => frame.set(x, y, Color::rgb(220, 50, 50)),
+            CellState::Floor => {}
+        }
+    }
+    frame
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CellState {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+impl CellState {
+    fn new(cell_char: char, row: usize, col: usize) -> ParseResult<CellState> {
+        match cell_char {
+            '.' => Ok(CellState::Floor),
+            'L' => Ok(CellState::Empty),
+            '#' => Ok(CellState::Occupied),
+            _ => Err(ParseError::new(row, col, "'.', 'L' or '#'", &cell_char.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for CellState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellState::Floor => write!(f, "."),
+            CellState::Empty => write!(f, "L"),
+            CellState::Occupied => write!(f, "#"),
+        }
+    }
+}
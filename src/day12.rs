@@ -0,0 +1,248 @@
+use crate::error::{parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    // The same rows are interpreted differently in the two parts
+    type Input = (Vec<Instruction1>, Vec<Instruction2>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<(Vec<Instruction1>, Vec<Instruction2>)> {
+        let rows = input.iter().enumerate();
+        Ok((
+            rows.clone().map(|(idx, r)| Instruction1::parse(r, idx)).collect::<ParseResult<_>>()?,
+            rows.map(|(idx, r)| Instruction2::parse(r, idx)).collect::<ParseResult<_>>()?,
+        ))
+    }
+
+    fn part1((instructions, _): &(Vec<Instruction1>, Vec<Instruction2>)) -> u32 {
+        let starting_position = Coordinates::new(0, 0);
+        let mut ship = Ship::new(starting_position.clone());
+
+        for instruction in instructions {
+            ship.process1(*instruction);
+        }
+        Coordinates::manhattan_distance(starting_position, ship.position)
+    }
+
+    fn part2((_, instructions): &(Vec<Instruction1>, Vec<Instruction2>)) -> u32 {
+        let starting_position = Coordinates::new(0, 0);
+        let mut ship = Ship::new(starting_position.clone());
+
+        for instruction in instructions {
+            ship.process2(*instruction);
+        }
+        Coordinates::manhattan_distance(starting_position, ship.position)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction1 {
+    MoveToDirection(Direction, i32),
+    MoveForward(i32),
+    TurnLeft(i32),
+    TurnRight(i32),
+}
+
+/*
+ * Splits an instruction into its action letter and numeric argument, e.g. "F10" into
+ * ('F', 10). Turns must be multiples of 90 degrees.
+ */
+fn split_instruction(instruction: &str, row: usize) -> ParseResult<(char, i32)> {
+    let action = instruction
+        .chars()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(row, 0, "an action"))?;
+    let arg = &instruction[action.len_utf8()..];
+    if !"NSEWLRF".contains(action) {
+        return Err(ParseError::new(row, 0, "one of N, S, E, W, L, R or F", &action.to_string()));
+    }
+    let arg_num = if arg.is_empty() {
+        return Err(ParseError::end_of_input(row, 1, "an argument"));
+    } else {
+        parse_token::<i32>(arg, row, 1, "a numeric argument")?
+    };
+    if (action == 'L' || action == 'R') && arg_num % 90 != 0 {
+        return Err(ParseError::new(row, 1, "a multiple of 90 degrees", arg));
+    }
+
+    Ok((action, arg_num))
+}
+
+impl Instruction1 {
+    fn parse(instruction: &str, row: usize) -> ParseResult<Instruction1> {
+        let (action, arg_num) = split_instruction(instruction, row)?;
+
+        Ok(match action {
+            'N' => Instruction1::MoveToDirection(Direction::North, arg_num),
+            'S' => Instruction1::MoveToDirection(Direction::South, arg_num),
+            'E' => Instruction1::MoveToDirection(Direction::East, arg_num),
+            'W' => Instruction1::MoveToDirection(Direction::West, arg_num),
+            'F' => Instruction1::MoveForward(arg_num),
+            'L' => Instruction1::TurnLeft(arg_num),
+            _ => Instruction1::TurnRight(arg_num),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction2 {
+    MoveWaypointToDirection(Direction, i32),
+    RotateWaypointLeft(i32),
+    RotateWaypointRight(i32),
+    MoveToWaypoint(i32),
+}
+
+impl Instruction2 {
+    fn parse(instruction: &str, row: usize) -> ParseResult<Instruction2> {
+        let (action, arg_num) = split_instruction(instruction, row)?;
+
+        Ok(match action {
+            'N' => Instruction2::MoveWaypointToDirection(Direction::North, arg_num),
+            'S' => Instruction2::MoveWaypointToDirection(Direction::South, arg_num),
+            'E' => Instruction2::MoveWaypointToDirection(Direction::East, arg_num),
+            'W' => Instruction2::MoveWaypointToDirection(Direction::West, arg_num),
+            'L' => Instruction2::RotateWaypointLeft(arg_num),
+            'R' => Instruction2::RotateWaypointRight(arg_num),
+            _ => Instruction2::MoveToWaypoint(arg_num),
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Coordinates {
+    x: i32,
+    y: i32
+}
+
+impl Coordinates {
+    fn new(x: i32, y: i32) -> Coordinates {
+        Coordinates {
+            x,
+            y
+        }
+    }
+
+    fn offset(&self, direction: Direction, distance: i32) -> Coordinates {
+        match direction {
+            Direction::North => self.offset_by(0, distance),
+            Direction::South => self.offset_by(0, -distance),
+            Direction::East => self.offset_by(distance, 0),
+            Direction::West => self.offset_by(-distance, 0),
+        }
+    }
+
+    fn offset_by(&self, x_offset: i32, y_offset: i32) -> Coordinates {
+        Coordinates {
+            x: self.x + x_offset,
+            y: self.y + y_offset,
+        }
+    }
+
+    fn rotate(&self, degrees: i32) -> Coordinates {
+        let left_turns = (degrees / 90).rem_euclid(4);
+        let (mut new_x, mut new_y) = (self.x, self.y);
+        for _ in 0..left_turns {
+            // Swap the coordinates, and negate the correct one
+            let swp = new_y;
+            new_y = new_x;
+            new_x = -swp;
+        }
+
+        Coordinates::new(new_x, new_y)
+    }
+
+    fn manhattan_distance(a: Coordinates, b: Coordinates) -> u32 {
+        ((b.x - a.x).abs() + (b.y - a.y).abs()) as u32
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn turn(&self, degrees: i32) -> Direction {
+        let directions = [
+            Direction::East,
+            Direction::North,
+            Direction::West,
+            Direction::South,
+        ];
+
+        let curr_idx = directions
+            .iter()
+            .position(|d| d == self)
+            .unwrap();
+        let new_idx = (curr_idx as i32 + (degrees / 90)).rem_euclid(4);
+
+        directions[new_idx as usize]
+    }
+}
+
+struct Ship {
+    position: Coordinates,
+    wp_rel_pos: Coordinates,
+    orientation: Direction,
+}
+
+impl Ship {
+    fn new(position: Coordinates) -> Ship {
+        Ship {
+            wp_rel_pos: position.offset_by(10, 1),
+            position,
+            orientation: Direction::East,
+        }
+    }
+
+    fn process1(&mut self, input: Instruction1) {
+        match input {
+            Instruction1::MoveToDirection(direction, distance) => {
+                self.position = self.position.offset(direction, distance)
+            }
+            Instruction1::MoveForward(distance) => {
+                self.position = self.position.offset(self.orientation, distance)
+            }
+            Instruction1::TurnLeft(degrees) => self.orientation = self.orientation.turn(degrees),
+            Instruction1::TurnRight(degrees) => self.orientation = self.orientation.turn(-degrees),
+        };
+    }
+
+    fn process2(&mut self, input: Instruction2) {
+        match input {
+            Instruction2::MoveWaypointToDirection(direction, distance) => {
+                self.wp_rel_pos = self.wp_rel_pos.offset(direction, distance);
+            },
+            Instruction2::MoveToWaypoint(count) => {
+                self.position = self.position.offset_by(self.wp_rel_pos.x * count, self.wp_rel_pos.y * count);
+            },
+            Instruction2::RotateWaypointLeft(degrees) => {
+                self.wp_rel_pos = self.wp_rel_pos.rotate(degrees);
+            },
+            Instruction2::RotateWaypointRight(degrees) => {
+                self.wp_rel_pos = self.wp_rel_pos.rotate(-degrees);
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+    use crate::Solution;
+
+    #[test]
+    fn parse_rejects_unknown_actions_and_turns() {
+        let error = Day12::parse(&["F10".to_string(), "X3".to_string()]).err().unwrap();
+        assert_eq!((2, 1, "X"), (error.line, error.column, error.found.as_str()));
+
+        let error = Day12::parse(&["R45".to_string()]).err().unwrap();
+        assert_eq!("line 1, column 2: expected a multiple of 90 degrees, found \"45\"", error.to_string());
+    }
+}
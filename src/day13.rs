@@ -0,0 +1,70 @@
+use crate::error::{column_of, parse_token, row_at, ParseError, ParseResult};
+use crate::number_theory::{solve_crt, Congruence};
+use crate::Solution;
+
+pub struct Day13;
+
+pub struct Notes {
+    // The earliest timestamp to depart at
+    earliest: u64,
+    // The bus ids in schedule order, None for the buses that are out of service
+    buses: Vec<Option<u64>>,
+}
+
+impl Solution for Day13 {
+    type Input = Notes;
+    type Output1 = u64;
+    type Output2 = Option<i64>;
+
+    fn parse(input: &[String]) -> ParseResult<Notes> {
+        let earliest = parse_token(row_at(input, 0, "the earliest timestamp")?, 0, 0, "the earliest timestamp")?;
+        let schedule = row_at(input, 1, "the bus schedule")?;
+        let buses = schedule
+            .split(',')
+            .map(|bus| match bus {
+                "x" => Ok(None),
+                _ => match parse_token(bus, 1, column_of(schedule, bus), "a bus id or 'x'")? {
+                    0 => Err(ParseError::new(1, column_of(schedule, bus), "a bus id above 0", bus)),
+                    id => Ok(Some(id)),
+                },
+            })
+            .collect::<ParseResult<Vec<Option<u64>>>>()?;
+        if buses.iter().all(Option::is_none) {
+            return Err(ParseError::new(1, 0, "at least one bus id", schedule));
+        }
+        Ok(Notes { earliest, buses })
+    }
+
+    fn part1(notes: &Notes) -> u64 {
+        find_earliest_bus(notes)
+    }
+
+    fn part2(notes: &Notes) -> Option<i64> {
+        find_earliest_aligned_timestamp(notes)
+    }
+}
+
+fn find_earliest_bus(notes: &Notes) -> u64 {
+    // A bus that departs right at the earliest timestamp means no waiting at all
+    let (bus, wait_time) = notes
+        .buses
+        .iter()
+        .flatten()
+        .map(|bus| (bus, (bus - notes.earliest % bus) % bus))
+        .min_by_key(|(_, wait_time)| *wait_time)
+        .unwrap();
+
+    bus * wait_time
+}
+
+fn find_earliest_aligned_timestamp(notes: &Notes) -> Option<i64> {
+    // The bus at index idx departs at t + idx, so t = -idx (mod bus)
+    let congruences: Vec<Congruence> = notes
+        .buses
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, bus)| bus.map(|bus| Congruence::new(-(idx as i64), bus as i64)))
+        .collect();
+
+    solve_crt(&congruences).ok().map(|solution| solution.residue)
+}
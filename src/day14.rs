@@ -0,0 +1,212 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::Solution;
+
+lazy_static! {
+    static ref MASK_UPDATE_RE: Regex = Regex::new(r"^mask = ([X01]+)$").unwrap();
+    static ref MEM_WRITE_RE: Regex = Regex::new(r"mem\[(\d+)\] = (\d+)$").unwrap();
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Operation>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Operation>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| parse_operation(r, idx))
+            .collect()
+    }
+
+    fn part1(operations: &Vec<Operation>) -> u64 {
+        // The values are 36 bits long, so u32 is insufficient
+        // All values are initialized as 0, so only the written ones need storing
+        let mut memory: HashMap<u64, u64> = HashMap::new();
+        let initial_mask: Bitmask = "X".repeat(36).parse::<Bitmask>().unwrap();
+        let mut mask = &initial_mask;
+
+        for operation in operations {
+            match operation {
+                Operation::UpdateMask(new_mask) => mask = new_mask,
+                Operation::Write(mem_idx, value) => {
+                    memory.insert(*mem_idx, mask.apply(*value));
+                }
+            }
+        }
+
+        memory.values().sum()
+    }
+
+    fn part2(operations: &Vec<Operation>) -> u64 {
+        let mut memory: HashMap<u64, u64> = HashMap::new();
+        let initial_mask: Bitmask = "X".repeat(36).parse::<Bitmask>().unwrap();
+        let mut mask = &initial_mask;
+
+        for operation in operations {
+            match operation {
+                Operation::UpdateMask(new_mask) => mask = new_mask,
+                Operation::Write(mem_idx, value) => {
+                    // Brute-force through all memory indices, check if the index maches,
+                    // excluding the floating indices
+                    for idx in get_indices(*mem_idx, mask) {
+                        memory.insert(idx, *value);
+                    }
+                }
+            }
+        }
+
+        memory.values().sum()
+    }
+}
+
+fn parse_operation(row: &str, row_idx: usize) -> ParseResult<Operation> {
+    if let Some(captures) = MASK_UPDATE_RE.captures(row) {
+        let mask = captures.get(1).unwrap().as_str();
+        if mask.len() != 36 {
+            return Err(ParseError::new(row_idx, column_of(row, mask), "a 36-bit mask", mask));
+        }
+        Ok(Operation::UpdateMask(parse_token(mask, row_idx, column_of(row, mask), "a 36-bit mask")?))
+    } else if let Some(captures) = MEM_WRITE_RE.captures(row) {
+        let mem_idx = captures.get(1).unwrap().as_str();
+        let value = captures.get(2).unwrap().as_str();
+        Ok(Operation::Write(
+            parse_token(mem_idx, row_idx, column_of(row, mem_idx), "a memory address")?,
+            parse_token(value, row_idx, column_of(row, value), "a 36-bit value")?,
+        ))
+    } else {
+        Err(ParseError::new(row_idx, 0, "\"mask = <mask>\" or \"mem[<address>] = <value>\"", row))
+    }
+}
+
+pub enum Operation {
+    UpdateMask(Bitmask),
+    // Memory index and the written value
+    Write(u64, u64),
+}
+
+fn get_indices(index: u64, mask: &Bitmask) -> Vec<u64> {
+    let floating_indices = &mask.floating_indices;
+    // Without floating bits, the mask only sets its ones
+    if floating_indices.is_empty() {
+        return vec![index | mask.ones];
+    }
+    let input_str = to_bitstring(index | mask.ones);
+
+    // Initialize the first substring up to the first floating bit
+    let baseline = input_str[..floating_indices[0]].to_owned();
+    let mut work: Vec<String> = vec![baseline];
+
+    // On every step, add two versions of all existing strings (one suffixed with 0, one
+    // with 1) and prepend the strings until the index
+    for i in 0..floating_indices.len() {
+        let this_floater: usize = floating_indices[i] + 1;
+        let next_floater: usize = match i {
+            val if val < floating_indices.len() - 1 => floating_indices[val + 1],
+            _ => input_str.len(),
+        };
+        let substr = &input_str[this_floater..next_floater];
+
+        let mut new_entries: Vec<String> = Vec::new();
+        new_entries.extend(work.iter().map(|x| [x, "0", substr].concat()));
+        new_entries.extend(work.iter().map(|x| [x, "1", substr].concat()));
+
+        work.clear();
+        work.append(&mut new_entries);
+    }
+
+    work.iter()
+        .map(|x| u64::from_str_radix(x, 2).unwrap())
+        .collect()
+}
+
+fn to_bitstring(input: u64) -> String {
+    format!("{:0>36}", format!("{:b}", input))
+}
+
+pub struct Bitmask {
+    zeroes: u64,
+    ones: u64,
+    floating_indices: Vec<usize>,
+}
+
+impl Bitmask {
+    fn apply(&self, input: u64) -> u64 {
+        input & self.zeroes | self.ones
+    }
+}
+
+impl FromStr for Bitmask {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Bitmask {
+            zeroes: u64::from_str_radix(&s.replace("X", "1"), 2).unwrap(),
+            ones: u64::from_str_radix(&s.replace("X", "0"), 2).unwrap(),
+            floating_indices: s
+                .char_indices()
+                .filter(|c| c.1 == 'X')
+                .map(|c| c.0)
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day14;
+    use crate::Solution;
+
+    #[test]
+    fn writes_to_any_address() {
+        let input: Vec<String> = [
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XX",
+            "mem[70000] = 11",
+            "mem[68719476735] = 1",
+        ]
+        .iter()
+        .map(|row| row.to_string())
+        .collect();
+        let operations = Day14::parse(&input).unwrap();
+        assert_eq!(15 + 5, Day14::part1(&operations));
+
+        // A mask without floating bits writes to a single address
+        let input: Vec<String> = ["mask = 000000000000000000000000000000000001", "mem[8] = 3", "mem[9] = 4"]
+            .iter()
+            .map(|row| row.to_string())
+            .collect();
+        let operations = Day14::parse(&input).unwrap();
+        assert_eq!(1 + 1, Day14::part1(&operations));
+        assert_eq!(4, Day14::part2(&operations));
+    }
+
+    #[test]
+    fn verify_get_indices() {
+        assert_eq!(
+            vec![1, 1 + 2_u64.pow(34)],
+            super::get_indices(
+                1,
+                &"0X0000000000000000000000000000000000"
+                    .parse::<super::Bitmask>()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            vec![1, 1 + 2_u64.pow(35)],
+            super::get_indices(
+                1,
+                &"X00000000000000000000000000000000000"
+                    .parse::<super::Bitmask>()
+                    .unwrap()
+            )
+        );
+    }
+}
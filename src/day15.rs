@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::error::{column_of, parse_token, row_at, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<u64>> {
+        parse_starting_numbers(row_at(input, 0, "the starting numbers")?)
+    }
+
+    fn part1(starting_numbers: &Vec<u64>) -> u64 {
+        get_nth_in_serise(starting_numbers, 2020)
+    }
+
+    fn part2(starting_numbers: &Vec<u64>) -> u64 {
+        get_nth_in_serise(starting_numbers, 30000000)
+    }
+}
+
+fn parse_starting_numbers(input: &str) -> ParseResult<Vec<u64>> {
+    let numbers: Vec<u64> = input
+        .split_terminator(',')
+        .map(|val| parse_token(val, 0, column_of(input, val), "a starting number"))
+        .collect::<ParseResult<_>>()?;
+    if numbers.is_empty() {
+        return Err(ParseError::end_of_input(0, 0, "a starting number"));
+    }
+    Ok(numbers)
+}
+
+fn get_nth_in_serise(starting_numbers: &[u64], n: usize) -> u64 {
+    let input_values: Vec<(u64, usize)> = starting_numbers
+        .iter()
+        .enumerate()
+        .map(|(idx, val)| (*val, idx))
+        .collect();
+    let (last, rest) = input_values.split_last().unwrap();
+    let (mut latest_value, mut latest_idx) = last;
+    let mut last_occurrences: HashMap<u64, usize> = rest.iter().cloned().collect();
+
+    while latest_idx < n - 1 {
+        let last_occurrence = last_occurrences.get(&latest_value);
+        let next_value = match last_occurrence {
+            Some(last_instance_idx) => (latest_idx - last_instance_idx) as u64,
+            None                    => 0
+        };
+        last_occurrences.insert(latest_value, latest_idx);
+        latest_idx += 1;
+        latest_value = next_value;
+    }
+
+    latest_value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day15;
+    use crate::Solution;
+
+    #[test]
+    fn starting_numbers_are_required() {
+        let error = Day15::parse(&["".to_string()]).unwrap_err();
+        assert_eq!("line 1, column 1: expected a starting number, found end of input", error.to_string());
+        assert_eq!(vec![0, 3, 6], Day15::parse(&["0,3,6".to_string()]).unwrap());
+    }
+}
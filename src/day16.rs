@@ -0,0 +1,146 @@
+use crate::assignment::assign_up_to;
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::helpers::records::sections;
+use crate::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    // Field rules, own ticket and nearby tickets
+    type Input = (Vec<FieldRule>, Ticket, Vec<Ticket>);
+    type Output1 = u32;
+    type Output2 = Option<u64>;
+
+    fn parse(input: &[String]) -> ParseResult<(Vec<FieldRule>, Ticket, Vec<Ticket>)> {
+        parse_input(input)
+    }
+
+    fn part1((rules, _, other_tickets): &(Vec<FieldRule>, Ticket, Vec<Ticket>)) -> u32 {
+        let mut result = 0;
+        for ticket in other_tickets {
+            for field in &ticket.field_values {
+                let field_valid = rules.iter().any(|r| r.valid_values.contains(field));
+                if !field_valid {
+                    result += field;
+                }
+            }
+        }
+        result
+    }
+
+    fn part2((rules, own_ticket, other_tickets): &(Vec<FieldRule>, Ticket, Vec<Ticket>)) -> Option<u64> {
+        let valid_tickets: Vec<&Ticket> = other_tickets
+            .iter()
+            .filter(|t| {
+                for field in &t.field_values {
+                    if !rules.iter().any(|r| r.valid_values.contains(field)) {
+                        return false;
+                    }
+                }
+                true
+            })
+            .collect();
+
+        let column_count = own_ticket.field_values.len();
+        let potential_indices: Vec<Vec<usize>> = rules
+            .iter()
+            .map(|r| r.potential_indices(column_count, &valid_tickets))
+            .collect();
+        // Only a unique assignment of the fields to columns gives an answer
+        let indices = assign_up_to(&potential_indices, 2).unique()?;
+
+        Some(
+            rules
+                .iter()
+                .zip(indices)
+                .filter(|(r, _)| r.name.starts_with("departure"))
+                .map(|(_, idx)| own_ticket.field_values[idx] as u64)
+                .product(),
+        )
+    }
+}
+
+fn parse_input(input: &[String]) -> ParseResult<(Vec<FieldRule>, Ticket, Vec<Ticket>)> {
+    let mut sections = sections(input);
+    let rules: Vec<FieldRule> = sections
+        .next_section("the field rules")?
+        .lines()
+        .map(|(idx, row)| FieldRule::parse(row, idx))
+        .collect::<ParseResult<_>>()?;
+
+    let own_section = sections.next_section("\"your ticket:\"")?.after_header("your ticket:")?;
+    let (own_idx, own_row) = own_section
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(own_section.start, 0, "your ticket"))?;
+    let own_ticket = Ticket::parse(own_row, own_idx)?;
+    if let Some((idx, row)) = own_section.lines().nth(1) {
+        return Err(ParseError::new(idx, 0, "an empty line", row));
+    }
+
+    let other_tickets: Vec<Ticket> = sections
+        .next_section("\"nearby tickets:\"")?
+        .after_header("nearby tickets:")?
+        .lines()
+        .map(|(idx, row)| Ticket::parse(row, idx))
+        .collect::<ParseResult<_>>()?;
+    sections.expect_end()?;
+    Ok((rules, own_ticket, other_tickets))
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct FieldRule {
+    name: String,
+    valid_values: Vec<u32>,
+}
+
+impl FieldRule {
+    fn potential_indices(&self, column_count: usize, tickets: &[&Ticket]) -> Vec<usize> {
+        (0..column_count)
+            .filter(|i| {
+                tickets
+                    .iter()
+                    .all(|t| t.field_values.get(*i).is_some_and(|v| self.valid_values.contains(v)))
+            })
+            .collect()
+    }
+}
+
+impl FieldRule {
+    fn parse(s: &str, row: usize) -> ParseResult<FieldRule> {
+        let (name, intervals) = match s.find(": ") {
+            Some(pos) => (&s[..pos], &s[pos + 2..]),
+            None => return Err(ParseError::new(row, 0, "\"<field>: <intervals>\"", s)),
+        };
+        let mut valid_vals: Vec<u32> = Vec::new();
+        for interval in intervals.split_terminator(" or ") {
+            let col = column_of(s, interval);
+            let (start, end) = match interval.find('-') {
+                Some(pos) => (&interval[..pos], &interval[pos + 1..]),
+                None => return Err(ParseError::new(row, col, "an interval \"<min>-<max>\"", interval)),
+            };
+            let start: u32 = parse_token(start, row, col, "an interval start")?;
+            let end: u32 = parse_token(end, row, column_of(s, end), "an interval end")?;
+            valid_vals.extend(start..=end);
+        }
+        Ok(FieldRule {
+            name: name.to_owned(),
+            valid_values: valid_vals,
+        })
+    }
+}
+
+pub struct Ticket {
+    field_values: Vec<u32>,
+}
+
+impl Ticket {
+    fn parse(s: &str, row: usize) -> ParseResult<Ticket> {
+        Ok(Ticket {
+            field_values: s
+                .split_terminator(',')
+                .map(|v| parse_token(v, row, column_of(s, v), "a ticket field value"))
+                .collect::<ParseResult<_>>()?,
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use crate::automaton::{Automaton, Rule, SquareLattice};
+use crate::error::{ParseError, ParseResult};
+use crate::grid::Grid;
+use crate::visualization::{Color, Frame, FrameSink, NoFrames};
+use crate::Solution;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    // The initial slice, true for the active cubes
+    type Input = Grid<bool>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<Grid<bool>> {
+        Grid::parse(input, |c, row, col| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(ParseError::new(row, col, "'.' or '#'", &c.to_string())),
+        })
+    }
+
+    fn part1(input: &Grid<bool>) -> u32 {
+        simulate::<3>(input, 6, &mut NoFrames)
+    }
+
+    fn part2(input: &Grid<bool>) -> u32 {
+        simulate::<4>(input, 6, &mut NoFrames)
+    }
+
+    fn visualize(input: &Grid<bool>, part: u8, sink: &mut dyn FrameSink) -> bool {
+        match part {
+            1 => simulate::<3>(input, 6, sink),
+            _ => simulate::<4>(input, 6, sink),
+        };
+        true
+    }
+}
+
+/*
+ * Returns the active cubes of the initial slice, which is at 0 in all the dimensions
+ * after the first two.
+ */
+fn parse_state<const N: usize>(slice: &Grid<bool>) -> Vec<[i32; N]> {
+    slice
+        .iter()
+        .filter(|(_, active)| **active)
+        .map(|((col, row), _)| {
+            let mut cell = [0; N];
+            cell[0] = row as i32;
+            cell[1] = col as i32;
+            cell
+        })
+        .collect()
+}
+
+fn simulate<const N: usize>(initial_state: &Grid<bool>, rounds: u32, sink: &mut dyn FrameSink) -> u32 {
+    let rule = Rule::parse("B3/S23").unwrap();
+    let mut automaton = Automaton::new(SquareLattice::<N>::new(), rule, parse_state::<N>(initial_state));
+    if sink.enabled() {
+        sink.emit(state_to_frame(automaton.live_cells(), 0));
+    }
+
+    for round in 1..=rounds {
+        automaton.step();
+        if sink.enabled() {
+            sink.emit(state_to_frame(automaton.live_cells(), round));
+        }
+    }
+
+    automaton.population() as u32
+}
+
+/*
+ * Draws the x-y slices of the active cubes next to each other, z growing from left to
+ * right and w from top to bottom.
+ */
+fn state_to_frame<const N: usize>(state: &HashSet<[i32; N]>, round: u32) -> Frame {
+    // The missing dimensions of 3D cubes are always 0
+    let bounds = |dimension: usize| {
+        let coords = || state.iter().map(move |p| p.get(dimension).copied().unwrap_or(0));
+        let min = coords().min().unwrap_or(0);
+        let max = coords().max().unwrap_or(0);
+        (min, (max - min + 1) as usize)
+    };
+    let (min_x, height) = bounds(0);
+    let (min_y, width) = bounds(1);
+    let (min_z, z_count) = bounds(2);
+    let (min_w, w_count) = bounds(3);
+
+    let mut caption = format!("Cycle {}: z from {} to {}", round, min_z, min_z + z_count as i32 - 1);
+    if N == 4 {
+        caption.push_str(&format!(", w from {} to {}", min_w, min_w + w_count as i32 - 1));
+    }
+
+    // Leave a one cell gap between the slices
+    let mut frame = Frame::new(&caption, z_count * (width + 1) - 1, w_count * (height + 1) - 1, Color::BLACK);
+    for w in 0..w_count {
+        for z in 0..z_count {
+            for x in 0..height {
+                for y in 0..width {
+                    frame.set(z * (width + 1) + y, w * (height + 1) + x, Color::rgb(40, 40, 60));
+                }
+            }
+        }
+    }
+    for p in state {
+        let (z, w) = (p.get(2).copied().unwrap_or(0), p.get(3).copied().unwrap_or(0));
+        let x = (z - min_z) as usize * (width + 1) + (p[1] - min_y) as usize;
+        let y = (w - min_w) as usize * (height + 1) + (p[0] - min_x) as usize;
+        frame.set(x, y, Color::rgb(250, 210, 60));
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day17;
+    use crate::Solution;
+
+    #[test]
+    fn only_dots_and_hashes_are_cubes() {
+        let error = Day17::parse(&["xyz".to_string(), "abc".to_string()]).unwrap_err();
+        assert_eq!("line 1, column 1: expected '.' or '#', found \"x\"", error.to_string());
+        let slice = Day17::parse(&[".#.".to_string(), "..#".to_string(), "###".to_string()]).unwrap();
+        assert_eq!(112, Day17::part1(&slice));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::ParseResult;
+use crate::expression::{Expr, OperatorTable};
+use crate::Solution;
+
+pub struct Day18;
+
+/*
+ * The homework parsed with the precedence of both parts, since it decides the shape of
+ * the expression trees.
+ */
+pub struct Homework {
+    left_to_right: Vec<Expr>,
+    addition_first: Vec<Expr>,
+}
+
+impl Solution for Day18 {
+    type Input = Homework;
+    type Output1 = Option<i64>;
+    type Output2 = Option<i64>;
+
+    fn parse(input: &[String]) -> ParseResult<Homework> {
+        let parse_all = |table: &OperatorTable| -> ParseResult<Vec<Expr>> {
+            input
+                .iter()
+                .enumerate()
+                .map(|(idx, row)| table.parse(row, idx))
+                .collect()
+        };
+        Ok(Homework {
+            left_to_right: parse_all(&OperatorTable::left_to_right())?,
+            addition_first: parse_all(&OperatorTable::addition_first())?,
+        })
+    }
+
+    fn part1(homework: &Homework) -> Option<i64> {
+        sum_of_results(&homework.left_to_right)
+    }
+
+    fn part2(homework: &Homework) -> Option<i64> {
+        sum_of_results(&homework.addition_first)
+    }
+}
+
+// None if any expression or the sum overflows
+fn sum_of_results(expressions: &[Expr]) -> Option<i64> {
+    expressions
+        .iter()
+        .try_fold(0i64, |sum, expr| sum.checked_add(expr.evaluate()?))
+}
@@ -0,0 +1,301 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::helpers::records::sections;
+use crate::Solution;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    // The rulebook and the received messages
+    type Input = (HashMap<usize, Rule>, Vec<String>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<(HashMap<usize, Rule>, Vec<String>)> {
+        let mut sections = sections(input);
+        let mut rules: HashMap<usize, Rule> = HashMap::new();
+        for (idx, row) in sections.next_section("the rules")?.lines() {
+            let (rule_idx, rule) = match row.find(": ") {
+                Some(pos) => (&row[..pos], &row[pos + 2..]),
+                None => return Err(ParseError::new(idx, 0, "\"<index>: <rule>\"", row)),
+            };
+            rules.insert(
+                parse_token(rule_idx, idx, 0, "a rule index")?,
+                Rule::parse(row, rule, idx)?,
+            );
+        }
+        let messages = sections.next_section("the messages after an empty line")?.rows.to_vec();
+        sections.expect_end()?;
+
+        Ok((rules, messages))
+    }
+
+    fn part1((rules, messages): &(HashMap<usize, Rule>, Vec<String>)) -> u32 {
+        count_matches(&Grammar::new(rules), messages)
+    }
+
+    fn part2((rules, messages): &(HashMap<usize, Rule>, Vec<String>)) -> u32 {
+        // Task 2 replaces rules 8 and 11 with recursive ones
+        let mut rules = rules.clone();
+        for (idx, rule) in &[(8, "42 | 42 8"), (11, "42 31 | 42 11 31")] {
+            rules.insert(*idx, Rule::parse(rule, rule, 0).unwrap());
+        }
+        count_matches(&Grammar::new(&rules), messages)
+    }
+}
+
+fn count_matches(grammar: &Grammar, messages: &[String]) -> u32 {
+    messages.iter().filter(|m| grammar.matches(0, m)).count() as u32
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Symbol {
+    Rule(usize),
+    Char(char),
+}
+
+/*
+ * The rulebook as a context-free grammar: every rule is a list of alternative sequences
+ * of symbols. Rules that are referenced but not defined match nothing. Rules are
+ * renumbered with compact ids, so large rule numbers do not take any space.
+ */
+struct Grammar {
+    // The compact id of every rule number, defined or referenced
+    ids: HashMap<usize, usize>,
+    // The alternatives of every rule by id, with `Symbol::Rule` holding ids
+    productions: Vec<Vec<Vec<Symbol>>>,
+    // Whether the rule can match the empty string
+    nullable: Vec<bool>,
+}
+
+impl Grammar {
+    fn new(rules: &HashMap<usize, Rule>) -> Grammar {
+        let mut ids: HashMap<usize, usize> = HashMap::new();
+        for idx in rules.keys() {
+            let id = ids.len();
+            ids.entry(*idx).or_insert(id);
+        }
+        let mut productions: Vec<Vec<Vec<Symbol>>> = vec![Vec::new(); ids.len()];
+        for (idx, rule) in rules {
+            productions[ids[idx]] = Grammar::alternatives(rule)
+                .into_iter()
+                .map(|sequence| {
+                    sequence
+                        .into_iter()
+                        .map(|symbol| match symbol {
+                            // References to undefined rules get an id without alternatives
+                            Symbol::Rule(other) => {
+                                let id = ids.len();
+                                Symbol::Rule(*ids.entry(other).or_insert(id))
+                            }
+                            Symbol::Char(c) => Symbol::Char(c),
+                        })
+                        .collect()
+                })
+                .collect();
+        }
+        let rule_count = ids.len();
+        productions.resize(rule_count, Vec::new());
+
+        let mut nullable = vec![false; rule_count];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for idx in 0..rule_count {
+                let is_nullable = productions[idx].iter().any(|sequence| {
+                    sequence.iter().all(|symbol| match symbol {
+                        Symbol::Rule(other) => nullable[*other],
+                        Symbol::Char(_) => false,
+                    })
+                });
+                if is_nullable && !nullable[idx] {
+                    nullable[idx] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        Grammar {
+            ids,
+            productions,
+            nullable,
+        }
+    }
+
+    fn alternatives(rule: &Rule) -> Vec<Vec<Symbol>> {
+        match rule {
+            Rule::Alternatives(alternatives) => alternatives.iter().flat_map(Grammar::alternatives).collect(),
+            Rule::Concatenation(indices) => vec![indices.iter().map(|idx| Symbol::Rule(*idx)).collect()],
+            Rule::Strings(s) => vec![s.chars().map(Symbol::Char).collect()],
+        }
+    }
+
+    /*
+     * Returns whether the message matches the rule, with an Earley recognizer. It handles
+     * any recursion, left recursion included, in O(n^3) time for a message of length n.
+     */
+    fn matches(&self, start: usize, message: &str) -> bool {
+        let start = match self.ids.get(&start) {
+            Some(id) => *id,
+            None => return false,
+        };
+        let message: Vec<char> = message.chars().collect();
+        // Every set holds the items whose dot is right before the character at its index
+        let mut sets: Vec<Vec<Item>> = vec![Vec::new(); message.len() + 1];
+        let mut seen: Vec<HashSet<Item>> = vec![HashSet::new(); message.len() + 1];
+        let mut add = |sets: &mut Vec<Vec<Item>>, pos: usize, item: Item| {
+            if seen[pos].insert(item) {
+                sets[pos].push(item);
+            }
+        };
+        for alternative in 0..self.productions[start].len() {
+            add(&mut sets, 0, Item { rule: start, alternative, dot: 0, origin: 0 });
+        }
+
+        for pos in 0..=message.len() {
+            let mut next = 0;
+            while next < sets[pos].len() {
+                let item = sets[pos][next];
+                next += 1;
+                match self.productions[item.rule][item.alternative].get(item.dot) {
+                    // Scan
+                    Some(Symbol::Char(c)) => {
+                        if message.get(pos) == Some(c) {
+                            add(&mut sets, pos + 1, item.advance());
+                        }
+                    }
+                    // Predict, and skip over the rule right away if it can be empty, since
+                    // its completion would not see this item
+                    Some(Symbol::Rule(rule)) => {
+                        for alternative in 0..self.productions[*rule].len() {
+                            add(&mut sets, pos, Item { rule: *rule, alternative, dot: 0, origin: pos });
+                        }
+                        if self.nullable[*rule] {
+                            add(&mut sets, pos, item.advance());
+                        }
+                    }
+                    // Complete
+                    None => {
+                        let waiting: Vec<Item> = sets[item.origin]
+                            .iter()
+                            .filter(|waiting| {
+                                self.productions[waiting.rule][waiting.alternative].get(waiting.dot)
+                                    == Some(&Symbol::Rule(item.rule))
+                            })
+                            .map(|waiting| waiting.advance())
+                            .collect();
+                        for advanced in waiting {
+                            add(&mut sets, pos, advanced);
+                        }
+                    }
+                }
+            }
+        }
+
+        sets[message.len()].iter().any(|item| {
+            item.rule == start && item.origin == 0 && item.dot == self.productions[start][item.alternative].len()
+        })
+    }
+}
+
+/*
+ * An alternative of a rule that has matched up to the dot, starting at the origin.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Item {
+    rule: usize,
+    alternative: usize,
+    dot: usize,
+    origin: usize,
+}
+
+impl Item {
+    fn advance(self) -> Item {
+        Item { dot: self.dot + 1, ..self }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Alternatives(Vec<Rule>),
+    Concatenation(Vec<usize>),
+    Strings(String),
+}
+
+impl Rule {
+    /*
+     * Parses the rule `input`, which is a part of the given input `row`.
+     */
+    fn parse(row: &str, input: &str, row_idx: usize) -> ParseResult<Rule> {
+        let input = input.trim();
+        if input.contains('|') {
+            Ok(Rule::Alternatives(
+                input
+                    .split('|')
+                    .map(|alt| Rule::parse(row, alt, row_idx))
+                    .collect::<ParseResult<_>>()?,
+            ))
+        } else if input.starts_with('"') {
+            Ok(Rule::Strings(input.trim_matches('"').into()))
+        } else {
+            Ok(Rule::Concatenation(
+                input
+                    .split_whitespace()
+                    .map(|v| parse_token(v, row_idx, column_of(row, v), "a rule index"))
+                    .collect::<ParseResult<_>>()?,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Grammar, Rule};
+
+    fn grammar(rules: &[&str]) -> Grammar {
+        let rules: HashMap<usize, Rule> = rules
+            .iter()
+            .enumerate()
+            .map(|(idx, rule)| (idx, Rule::parse(rule, rule, idx).unwrap()))
+            .collect();
+        Grammar::new(&rules)
+    }
+
+    #[test]
+    fn left_recursion_is_matched() {
+        // Rule 0 is one or more "a"s followed by a "b"
+        let grammar = grammar(&["1 2", "1 3 | 3", "\"b\"", "\"a\""]);
+        assert!(grammar.matches(0, "ab"));
+        assert!(grammar.matches(0, "aaaab"));
+        assert!(!grammar.matches(0, "b"));
+        assert!(!grammar.matches(0, "aaba"));
+    }
+
+    #[test]
+    fn empty_and_undefined_rules_are_matched() {
+        // Rule 1 is any number of "a"s, including none
+        let grammar = grammar(&["1 2 1", "\"\" | 1 3", "\"b\"", "\"a\"", "0 9"]);
+        assert!(grammar.matches(0, "b"));
+        assert!(grammar.matches(0, "aabaaa"));
+        assert!(grammar.matches(1, ""));
+        assert!(!grammar.matches(0, "abab"));
+        // Rule 9 does not exist
+        assert!(!grammar.matches(4, "b"));
+        assert!(!grammar.matches(12, ""));
+    }
+
+    #[test]
+    fn large_rule_numbers_are_matched() {
+        let mut rules = HashMap::new();
+        rules.insert(0, Rule::parse("4000000000000 1", "4000000000000 1", 0).unwrap());
+        rules.insert(1, Rule::parse("\"b\"", "\"b\"", 1).unwrap());
+        rules.insert(4_000_000_000_000, Rule::parse("\"a\"", "\"a\"", 2).unwrap());
+        let grammar = Grammar::new(&rules);
+        assert!(grammar.matches(0, "ab"));
+        assert!(grammar.matches(4_000_000_000_000, "a"));
+        assert!(!grammar.matches(0, "b"));
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+use crate::grid::{Grid, Orientation};
+use crate::visualization::{Color, Frame, FrameSink, NoFrames};
+use crate::Solution;
+
+const SEAMONSTER_PATTERN: [[bool; 20]; 3] = [
+    [
+        false, false, false, false, false, false, false, false, false, false, false, false, false,
+        false, false, false, false, false, true, false,
+    ],
+    [
+        true, false, false, false, false, true, true, false, false, false, false, true, true,
+        false, false, false, false, true, true, true,
+    ],
+    [
+        false, true, false, false, true, false, false, true, false, false, true, false, false,
+        true, false, false, true, false, false, false,
+    ],
+];
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+    type Output1 = Option<u64>;
+    type Output2 = Option<u64>;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Tile>> {
+        // Every tile takes 11 rows and is followed by an empty row
+        let content_len = input.iter().rposition(|r| !r.is_empty()).map_or(0, |idx| idx + 1);
+        let input = &input[..content_len];
+        let mut tiles = Vec::new();
+        for first_row in (0..input.len()).step_by(12) {
+            if first_row + 11 < input.len() && !input[first_row + 11].is_empty() {
+                return Err(ParseError::new(first_row + 11, 0, "an empty line", &input[first_row + 11]));
+            }
+            let last_row = (first_row + 11).min(input.len());
+            tiles.push(Tile::parse(&input[first_row..last_row], first_row)?);
+        }
+        if tiles.is_empty() {
+            return Err(ParseError::end_of_input(0, 0, "a tile"));
+        }
+        Ok(tiles)
+    }
+
+    fn part1(tiles: &Vec<Tile>) -> Option<u64> {
+        let placed_tiles = place_tiles(tiles.to_vec(), &mut NoFrames)?;
+
+        let (first_row, last_row) = (placed_tiles.first()?, placed_tiles.last()?);
+        let corners = [first_row.first()?, first_row.last()?, last_row.first()?, last_row.last()?];
+        corners.iter().try_fold(1_u64, |product, tile| product.checked_mul(tile.id))
+    }
+
+    fn part2(tiles: &Vec<Tile>) -> Option<u64> {
+        get_water_roughness(tiles, &mut NoFrames)
+    }
+
+    fn visualize(tiles: &Vec<Tile>, part: u8, sink: &mut dyn FrameSink) -> bool {
+        match part {
+            1 => {
+                place_tiles(tiles.to_vec(), sink);
+            }
+            _ => {
+                get_water_roughness(tiles, sink);
+            }
+        }
+        true
+    }
+}
+
+fn get_water_roughness(tiles: &[Tile], sink: &mut dyn FrameSink) -> Option<u64> {
+    let placed_tiles = place_tiles(tiles.to_vec(), sink)?;
+
+    let monster_size = SEAMONSTER_PATTERN
+        .iter()
+        .map(|r| r.iter().filter(|v| **v).count())
+        .sum::<usize>();
+
+    // The image is only oriented correctly if it shows sea monsters
+    let mut result = None;
+    let bitmap = tiles_to_bitmap(&placed_tiles);
+
+    for (idx, orientation) in Orientation::all().iter().enumerate() {
+        let flipped_bitmap = bitmap.orient(*orientation);
+        let seamonsters = find_seamonsters(&flipped_bitmap);
+        if sink.enabled() {
+            let caption = format!("Orientation {}: {} sea monsters", idx + 1, seamonsters.len());
+            sink.emit(bitmap_to_frame(&caption, &flipped_bitmap, &seamonsters));
+        }
+        let monster_count = seamonsters.len();
+        if monster_count > 0 {
+            let all_nonempty_pixels = flipped_bitmap.values().filter(|v| **v).count();
+            result = Some((all_nonempty_pixels - monster_count * monster_size) as u64);
+        }
+    }
+    result
+}
+
+/*
+ * Places the tiles next to each other so that their edges match, and returns the rows
+ * of the image. Returns None if some tiles do not fit anywhere or the placed tiles do
+ * not form a rectangle.
+ */
+fn place_tiles(tiles: Vec<Tile>, sink: &mut dyn FrameSink) -> Option<Vec<Vec<Tile>>> {
+    // Build a large grid for placing the tiles
+    const P_SIDE: usize = 100;
+    let orientations = Orientation::all();
+    let mut p: Grid<Option<Tile>> = Grid::filled(P_SIDE, P_SIDE, None);
+
+    let mut tiles_to_place = VecDeque::from(tiles);
+    let mut search_frontier: HashSet<(usize, usize)> = HashSet::new();
+    search_frontier.insert((P_SIDE / 2 - 1, P_SIDE / 2 - 1));
+    // The number of tiles that could not be placed since the last placed tile
+    let mut failed_in_a_row = 0;
+
+    'outer: while let Some(curr_tile) = tiles_to_place.pop_front() {
+        for o in &orientations {
+            let oriented_tile = curr_tile.orient(*o);
+
+            for pos in search_frontier.clone() {
+                if can_be_placed(&p, &oriented_tile, pos) {
+                    p[pos] = Some(oriented_tile);
+                    failed_in_a_row = 0;
+                    update_search_frontier(&p, pos, &mut search_frontier);
+                    if sink.enabled() {
+                        sink.emit(placed_tiles_to_frame(&p, tiles_to_place.len()));
+                    }
+                    continue 'outer;
+                }
+            }
+        }
+        // Tile could not be placed, push it back to the queue unless no tile left in
+        // the queue fits anymore
+        tiles_to_place.push_back(curr_tile);
+        failed_in_a_row += 1;
+        if failed_in_a_row == tiles_to_place.len() {
+            return None;
+        }
+    }
+
+    let rows: Vec<Vec<Tile>> = p
+        .rows()
+        .map(|r| r.iter().flatten().cloned().collect::<Vec<Tile>>())
+        .filter(|r| !r.is_empty())
+        .collect();
+    match rows.first() {
+        Some(first) if rows.iter().all(|r| r.len() == first.len()) => Some(rows),
+        _ => None,
+    }
+}
+
+fn update_search_frontier<T>(
+    area: &Grid<Option<T>>,
+    current_cell: (usize, usize),
+    search_frontier: &mut HashSet<(usize, usize)>,
+) {
+    search_frontier.remove(&current_cell);
+    for neighbour in area.neighbours4(current_cell) {
+        if area[neighbour].is_none() {
+            search_frontier.insert(neighbour);
+        }
+    }
+}
+
+fn can_be_placed(area: &Grid<Option<Tile>>, tile: &Tile, position: (usize, usize)) -> bool {
+    for (own_side, neighbor_side, direction) in &[
+        (Side::North, Side::South, (0, -1)),
+        (Side::East, Side::West, (1, 0)),
+        (Side::South, Side::North, (0, 1)),
+        (Side::West, Side::East, (-1, 0)),
+    ] {
+        if let Some(Some(neighbour)) = area.step(position, *direction).map(|pos| &area[pos]) {
+            if tile.get_side(*own_side) != neighbour.get_side(*neighbor_side) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/*
+ * Joins the tiles to a single image, leaving out the borders of every tile.
+ */
+fn tiles_to_bitmap(tiles: &[Vec<Tile>]) -> Grid<bool> {
+    const CONTENT_SIDE: usize = 8;
+
+    let width = tiles[0].len() * CONTENT_SIDE;
+    let height = tiles.len() * CONTENT_SIDE;
+    Grid::from_fn(width, height, |(x, y)| {
+        let tile = &tiles[y / CONTENT_SIDE][x / CONTENT_SIDE];
+        tile.pixels[(x % CONTENT_SIDE + 1, y % CONTENT_SIDE + 1)]
+    })
+}
+
+/*
+ * Draws the tiles placed so far with a one cell gap between them.
+ */
+fn placed_tiles_to_frame(area: &Grid<Option<Tile>>, unplaced_count: usize) -> Frame {
+    const TILE_SIDE: usize = 10;
+
+    let placed: Vec<((usize, usize), &Tile)> = area
+        .iter()
+        .filter_map(|(pos, tile)| tile.as_ref().map(|t| (pos, t)))
+        .collect();
+    let min_x = placed.iter().map(|(pos, _)| pos.0).min().unwrap_or(0);
+    let max_x = placed.iter().map(|(pos, _)| pos.0).max().unwrap_or(0);
+    let min_y = placed.iter().map(|(pos, _)| pos.1).min().unwrap_or(0);
+    let max_y = placed.iter().map(|(pos, _)| pos.1).max().unwrap_or(0);
+
+    let caption = format!("{} tiles placed, {} left", placed.len(), unplaced_count);
+    let width = (max_x - min_x + 1) * (TILE_SIDE + 1) - 1;
+    let height = (max_y - min_y + 1) * (TILE_SIDE + 1) - 1;
+    let mut frame = Frame::new(&caption, width, height, Color::BLACK);
+    for ((x, y), tile) in placed {
+        let (left, top) = ((x - min_x) * (TILE_SIDE + 1), (y - min_y) * (TILE_SIDE + 1));
+        for ((col, row), pixel) in tile.pixels.iter() {
+            let color = if *pixel { Color::rgb(120, 170, 230) } else { Color::rgb(10, 40, 90) };
+            frame.set(left + col, top + row, color);
+        }
+    }
+    frame
+}
+
+/*
+ * Draws the image with the pixels of the sea monsters at the given offsets highlighted.
+ */
+fn bitmap_to_frame(caption: &str, bitmap: &Grid<bool>, seamonsters: &[(usize, usize)]) -> Frame {
+    let mut frame = Frame::new(caption, bitmap.width(), bitmap.height(), Color::rgb(10, 40, 90));
+    for ((x, y), pixel) in bitmap.iter() {
+        if *pixel {
+            frame.set(x, y, Color::rgb(120, 170, 230));
+        }
+    }
+    for (x, y) in seamonsters {
+        for (j, pattern_row) in SEAMONSTER_PATTERN.iter().enumerate() {
+            for (i, pattern_pixel) in pattern_row.iter().enumerate() {
+                if *pattern_pixel {
+                    frame.set(x + i, y + j, Color::rgb(60, 200, 90));
+                }
+            }
+        }
+    }
+    frame
+}
+
+/*
+ * Returns the offsets (x, y) of the top left corners of the sea monsters in the image.
+ */
+fn find_seamonsters(input: &Grid<bool>) -> Vec<(usize, usize)> {
+    let mut seamonsters = Vec::new();
+
+    // The image may be smaller than a sea monster
+    for y in 0..(input.height() + 1).saturating_sub(SEAMONSTER_PATTERN.len()) {
+        for x in 0..(input.width() + 1).saturating_sub(SEAMONSTER_PATTERN[0].len()) {
+            // (i,j) is the offset for the seamonster pattern
+            let pattern_matched = SEAMONSTER_PATTERN.iter().enumerate().all(|(j, pattern_row)| {
+                pattern_row
+                    .iter()
+                    .enumerate()
+                    .all(|(i, pattern_pixel)| !pattern_pixel || input[(x + i, y + j)])
+            });
+            if pattern_matched {
+                seamonsters.push((x, y));
+            }
+        }
+    }
+    seamonsters
+}
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    id: u64,
+    pixels: Grid<bool>,
+}
+
+impl Tile {
+    /*
+     * Parses a tile from its rows, the first of which is the row `first_row` of the input.
+     */
+    fn parse(input: &[String], first_row: usize) -> ParseResult<Tile> {
+        let header = &input[0];
+        let id = match header.strip_prefix("Tile ").and_then(|h| h.strip_suffix(':')) {
+            Some(id) => parse_token::<u64>(id, first_row, column_of(header, id), "a tile id")?,
+            None => return Err(ParseError::new(first_row, 0, "\"Tile <id>:\"", header)),
+        };
+        // Cells are always 10x10
+        if input.len() < 11 {
+            return Err(ParseError::end_of_input(first_row + input.len(), 0, "a row of 10 pixels"));
+        }
+        let mut values = Vec::new();
+        for (offset, r) in input[1..=10].iter().enumerate() {
+            let row = first_row + 1 + offset;
+            let mut pixels = Vec::new();
+            for (col, c) in r.chars().enumerate() {
+                match c {
+                    '#' | '.' if col < 10 => pixels.push(c == '#'),
+                    '#' | '.' => return Err(ParseError::new(row, col, "end of the row", &c.to_string())),
+                    _ => return Err(ParseError::new(row, col, "'#' or '.'", &c.to_string())),
+                }
+            }
+            if pixels.len() < 10 {
+                return Err(ParseError::end_of_input(row, pixels.len(), "'#' or '.'"));
+            }
+            values.push(pixels);
+        }
+
+        Ok(Tile {
+            id,
+            pixels: Grid::from_rows(values),
+        })
+    }
+
+    fn orient(&self, orientation: Orientation) -> Tile {
+        Tile {
+            id: self.id,
+            pixels: self.pixels.orient(orientation),
+        }
+    }
+
+    fn get_side(&self, side: Side) -> Vec<bool> {
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        match side {
+            Side::North => self.pixels.row(0).to_vec(),
+            Side::East => self.pixels.column(width - 1).copied().collect(),
+            Side::South => self.pixels.row(height - 1).to_vec(),
+            Side::West => self.pixels.column(0).copied().collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Copy)]
+enum Side {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day20;
+    use crate::Solution;
+
+    fn tile(id: u64, pixel: char) -> Vec<String> {
+        let mut rows = vec![format!("Tile {}:", id)];
+        rows.extend((0..10).map(|_| pixel.to_string().repeat(10)));
+        rows
+    }
+
+    #[test]
+    fn tiles_that_do_not_fit_are_unsolved() {
+        let mut input = tile(1, '.');
+        input.push(String::new());
+        input.extend(tile(2, '#'));
+        let tiles = Day20::parse(&input).unwrap();
+        assert_eq!(None, Day20::part1(&tiles));
+        assert_eq!(None, Day20::part2(&tiles));
+
+        // A single tile is smaller than a sea monster
+        let tiles = Day20::parse(&tile(3, '#')).unwrap();
+        assert_eq!(Some(81), Day20::part1(&tiles));
+        assert_eq!(None, Day20::part2(&tiles));
+    }
+
+    #[test]
+    fn at_least_one_tile_is_required() {
+        let error = Day20::parse(&[]).unwrap_err();
+        assert_eq!("line 1, column 1: expected a tile, found end of input", error.to_string());
+        assert!(Day20::parse(&["".to_string(), "".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashSet};
+
+use crate::assignment::assign_up_to;
+use crate::error::{ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+    type Output1 = u64;
+    type Output2 = Option<String>;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<Food>> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| Food::parse(r, idx))
+            .collect()
+    }
+
+    fn part1(foods: &Vec<Food>) -> u64 {
+        let candidates = find_candidates(foods);
+        let suspicious: HashSet<&str> = candidates
+            .ingredient_candidates
+            .iter()
+            .flatten()
+            .map(|idx| candidates.ingredients[*idx])
+            .collect();
+
+        // Count the ingredients that cannot contain any allergen
+        foods
+            .iter()
+            .flat_map(|f| &f.ingredients)
+            .filter(|i| !suspicious.contains(i.as_str()))
+            .count() as u64
+    }
+
+    fn part2(foods: &Vec<Food>) -> Option<String> {
+        let candidates = find_candidates(foods);
+        // Only a unique assignment of the allergens to ingredients gives an answer
+        let assignment = assign_up_to(&candidates.ingredient_candidates, 2).unique()?;
+
+        // The allergens are already in alphabetical order
+        Some(
+            assignment
+                .into_iter()
+                .map(|idx| candidates.ingredients[idx])
+                .collect::<Vec<&str>>()
+                .join(","),
+        )
+    }
+}
+
+struct Candidates<'a> {
+    // All ingredients, in alphabetical order
+    ingredients: Vec<&'a str>,
+    // For every allergen in alphabetical order, the indices of the ingredients that may
+    // contain it
+    ingredient_candidates: Vec<Vec<usize>>,
+}
+
+fn find_candidates(foods: &[Food]) -> Candidates<'_> {
+    let ingredients: Vec<&str> = foods
+        .iter()
+        .flat_map(|f| &f.ingredients)
+        .map(String::as_str)
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .collect();
+    let allergens: BTreeSet<&String> = foods.iter().flat_map(|f| &f.allergens).collect();
+
+    // An allergen can only be in the ingredients of every food it is listed for
+    let ingredient_candidates = allergens
+        .into_iter()
+        .map(|allergen| {
+            let mut affected = foods.iter().filter(|f| f.allergens.contains(allergen));
+            let mut potential_ingredients = affected.next().unwrap().ingredients.clone();
+            for food in affected {
+                potential_ingredients.retain(|i| food.ingredients.contains(i));
+            }
+            ingredients
+                .iter()
+                .enumerate()
+                .filter(|(_, i)| potential_ingredients.contains(**i))
+                .map(|(idx, _)| idx)
+                .collect()
+        })
+        .collect();
+
+    Candidates {
+        ingredients,
+        ingredient_candidates,
+    }
+}
+
+#[derive(Debug)]
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+impl Food {
+    fn parse(input: &str, row: usize) -> ParseResult<Food> {
+        let (ingredients, allergens) = match input.find(" (contains ") {
+            Some(pos) => (&input[..pos], &input[pos + " (contains ".len()..]),
+            None => {
+                let col = input.chars().count();
+                return Err(ParseError::end_of_input(row, col, "\" (contains <allergens>)\""));
+            }
+        };
+        let allergens = allergens.strip_suffix(')').ok_or_else(|| {
+            ParseError::end_of_input(row, input.chars().count(), "')'")
+        })?;
+
+        Ok(Food {
+            ingredients: ingredients
+                .split(' ')
+                .map(str::to_string)
+                .collect::<HashSet<String>>(),
+            allergens: allergens
+                .split(", ")
+                .map(str::to_string)
+                .collect::<HashSet<String>>(),
+        })
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::VecDeque;
+
+use crate::error::{parse_token, ParseError, ParseResult};
+use crate::helpers::records::{sections, Section};
+use crate::Solution;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (VecDeque<u32>, VecDeque<u32>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<(VecDeque<u32>, VecDeque<u32>)> {
+        parse_stacks(input)
+    }
+
+    fn part1(stacks: &(VecDeque<u32>, VecDeque<u32>)) -> u32 {
+        let (mut p1_stack, mut p2_stack) = stacks.clone();
+        while p1_stack.is_empty() == p2_stack.is_empty() {
+            let p1_card = p1_stack.pop_front().unwrap();
+            let p2_card = p2_stack.pop_front().unwrap();
+
+            if p1_card > p2_card {
+                p1_stack.push_back(p1_card);
+                p1_stack.push_back(p2_card);
+            } else {
+                p2_stack.push_back(p2_card);
+                p2_stack.push_back(p1_card);
+            }
+        }
+        let winning_stack = match p1_stack.is_empty() {
+            true => p2_stack,
+            false => p1_stack,
+        };
+
+        count_score(&winning_stack)
+    }
+
+    fn part2(stacks: &(VecDeque<u32>, VecDeque<u32>)) -> u32 {
+        let (mut p1_stack, mut p2_stack) = stacks.clone();
+
+        let (_, result) = play_recursive_combat(&mut p1_stack, &mut p2_stack);
+        result
+    }
+}
+
+fn play_recursive_combat(p1_stack: &mut VecDeque<u32>, p2_stack: &mut VecDeque<u32>) -> (u8, u32) {
+    let mut round_history: Vec<String> = Vec::new();
+
+    while p1_stack.is_empty() == p2_stack.is_empty() {
+        // If there was a previous round in this game that had exactly the same cards in
+        // the same order in the same players' decks, the game instantly ends in a win for
+        // player 1.
+        let round_desc = serialize_round(p1_stack, p2_stack);
+        if round_history.contains(&round_desc) {
+            return (1, count_score(p1_stack));
+        }
+        round_history.push(round_desc);
+
+        let p1_card = p1_stack.pop_front().unwrap();
+        let p1_card_u = p1_card as usize;
+        let p2_card = p2_stack.pop_front().unwrap();
+        let p2_card_u = p2_card as usize;
+
+        let p1_wins_round = if p1_stack.len() >= p1_card_u && p2_stack.len() >= p2_card_u {
+            // If both players have at least as many cards remaining in their deck as the
+            // value of the card they just drew, the winner of the round is determined by
+            // playing a new game of Recursive Combat
+            let (winner, _) = play_recursive_combat(
+                &mut p1_stack.iter().cloned().take(p1_card_u).collect(),
+                &mut p2_stack.iter().cloned().take(p2_card_u).collect(),
+            );
+
+            winner == 1
+        } else {
+            // Otherwise, at least one player must not have enough cards left in their
+            // deck to recurse; the winner of the round is the player with the
+            // higher-value card.
+            p1_card > p2_card
+        };
+
+        if p1_wins_round {
+            p1_stack.push_back(p1_card);
+            p1_stack.push_back(p2_card);
+        } else {
+            p2_stack.push_back(p2_card);
+            p2_stack.push_back(p1_card);
+        }
+    }
+
+    if p1_stack.is_empty() {
+        (2, count_score(p2_stack))
+    } else {
+        (1, count_score(p1_stack))
+    }
+}
+
+fn serialize_round(p1_stack: &VecDeque<u32>, p2_stack: &VecDeque<u32>) -> String {
+    [
+        "P1".to_string(),
+        p1_stack
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join("#"),
+        "P2".to_string(),
+        p2_stack
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join("#"),
+    ]
+    .join("//")
+}
+
+fn count_score(stack: &VecDeque<u32>) -> u32 {
+    // The topmost (i.e. first) card gets multiplied by the stack size, and the bottommost
+    // one is multiplied by 1, so the indices need to be subtracted from the stack's size
+    // to get correct multipliers
+    let stack_size = stack.len();
+    stack
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| (stack_size - idx) as u32 * value)
+        .sum()
+}
+
+fn parse_stacks(input: &[String]) -> ParseResult<(VecDeque<u32>, VecDeque<u32>)> {
+    let mut sections = sections(input);
+    let p1_stack = parse_deck(sections.next_section("the deck of player 1")?, "Player 1:")?;
+    let p2_stack = parse_deck(sections.next_section("the deck of player 2")?, "Player 2:")?;
+    sections.expect_end()?;
+
+    Ok((p1_stack, p2_stack))
+}
+
+fn parse_deck(section: Section, header: &str) -> ParseResult<VecDeque<u32>> {
+    let cards = section.after_header(header)?;
+    if cards.rows.is_empty() {
+        return Err(ParseError::end_of_input(cards.start, 0, "a card value"));
+    }
+    // A card of 0 would start a sub-game with no cards in recursive combat
+    cards
+        .lines()
+        .map(|(idx, row)| match parse_token(row, idx, 0, "a card value")? {
+            0 => Err(ParseError::new(idx, 0, "a positive card value", row)),
+            card => Ok(card),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day22;
+    use crate::Solution;
+
+    #[test]
+    fn decks_need_cards() {
+        let input: Vec<String> = ["Player 1:", "", "Player 2:", "3"].iter().map(|row| row.to_string()).collect();
+        let error = Day22::parse(&input).unwrap_err();
+        assert_eq!("line 2, column 1: expected a card value, found end of input", error.to_string());
+    }
+
+    #[test]
+    fn card_values_are_positive() {
+        let input: Vec<String> = ["Player 1:", "0", "", "Player 2:", "0"].iter().map(|row| row.to_string()).collect();
+        let error = Day22::parse(&input).unwrap_err();
+        assert_eq!("line 2, column 1: expected a positive card value, found \"0\"", error.to_string());
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+
+use crate::error::{row_at, ParseError, ParseResult};
+use crate::Solution;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<u64>;
+    type Output1 = String;
+    type Output2 = u64;
+
+    fn parse(input: &[String]) -> ParseResult<Vec<u64>> {
+        parse_cups(row_at(input, 0, "the cup labels")?)
+    }
+
+    fn part1(cups: &Vec<u64>) -> String {
+        play_short_game(cups)
+    }
+
+    fn part2(cups: &Vec<u64>) -> u64 {
+        play_long_game(cups)
+    }
+}
+
+/*
+ * Parses the labels of the cups, which have to be the numbers from 1 to the number of
+ * cups, each used once. A round picks up three cups and needs a fourth one besides the
+ * current cup as the destination, so there have to be at least 5 cups.
+ */
+fn parse_cups(input: &str) -> ParseResult<Vec<u64>> {
+    let cups: Vec<u64> = input
+        .chars()
+        .enumerate()
+        .map(|(col, c)| match c.to_digit(10) {
+            Some(d) => Ok(d as u64),
+            None => Err(ParseError::new(0, col, "a cup label digit", &c.to_string())),
+        })
+        .collect::<ParseResult<_>>()?;
+    if cups.len() < 5 {
+        return Err(ParseError::end_of_input(0, cups.len(), "at least 5 cup labels"));
+    }
+
+    let mut seen = vec![false; cups.len() + 1];
+    for (col, label) in cups.iter().enumerate() {
+        let expected = || format!("a cup label from 1 to {} that is not used yet", cups.len());
+        match seen.get_mut(*label as usize) {
+            Some(used) if *label > 0 && !*used => *used = true,
+            _ => return Err(ParseError::new(0, col, &expected(), &label.to_string())),
+        }
+    }
+
+    Ok(cups)
+}
+
+fn play_short_game(init_cups: &[u64]) -> String {
+    let mut cups = init_cups.iter().copied().collect::<VecDeque<u64>>();
+
+    for _ in 0..100 {
+        play_round(&mut cups);
+    }
+
+    let final_state = cups
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join("");
+    final_state.split('1').rev().collect()
+}
+
+fn play_round(cups: &mut VecDeque<u64>) {
+    let cup_count = cups.len() as u64;
+
+    let current_value = cups.pop_front().unwrap();
+    let shifted_values = [cups.pop_front().unwrap(),
+        cups.pop_front().unwrap(),
+        cups.pop_front().unwrap()];
+
+    let mut target_value = current_value;
+
+    while target_value == current_value || shifted_values.contains(&target_value) {
+        if target_value == 1 {
+            target_value = cup_count;
+        } else {
+            target_value -= 1;
+        }
+    }
+
+    let target_idx = cups.iter().position(|v| v == &target_value).unwrap();
+
+    for s in shifted_values.iter().rev() {
+        cups.insert(target_idx + 1, *s);
+    }
+    // Shift the first item to last
+    cups.push_back(current_value);
+}
+
+fn play_long_game(labels: &[u64]) -> u64 {
+    const CUP_COUNT: usize = 1_000_000;
+    // Create an array with 1 000 001 elements, indices ranging from 0 to 1 000 000.
+    // Since modeling a linked list is quite difficult in Rust, simulate a linked list's
+    // behaviour with this array. The array indices are list's elements (running from 1
+    // to 1 000 000), and the values are next elements' values. The list length is longer
+    // by one so the indices match, element 0 will not be used.
+    let mut cups = vec![0; CUP_COUNT+1];
+    
+    let init_cups = labels
+        .iter()
+        .map(|c| *c as usize)
+        .chain((labels.len() + 1)..=CUP_COUNT)
+        .collect::<Vec<usize>>();
+    for i in 0..init_cups.len() {
+        cups[init_cups[i]] = init_cups[(i+1) % init_cups.len()];
+    }
+
+    let mut current = init_cups[0];
+    for _ in 0..10_000_000 {
+        let first = cups[current];
+        let second = cups[first];
+        let third = cups[second];
+
+        // Change current value to point to the one after the three removed values
+        cups[current] = cups[third];
+
+        let mut target_value = current;
+        while [current, first, second, third].contains(&target_value) {
+            if target_value == 1{
+                target_value = CUP_COUNT;
+            } else {
+                target_value -= 1;
+            }
+        }
+        let target_next = cups[target_value];
+        cups[target_value] = first;
+        cups[third] = target_next;
+
+        current = cups[current];
+    }
+    (cups[1] as u64) * (cups[cups[1]] as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_cups;
+
+    #[test]
+    fn labels_are_1_to_the_cup_count() {
+        assert_eq!(vec![3, 8, 9, 1, 2, 5, 4, 6, 7], parse_cups("389125467").unwrap());
+        let error = |input: &str| parse_cups(input).unwrap_err().to_string();
+        assert_eq!("line 1, column 3: expected at least 5 cup labels, found end of input", error("12"));
+        assert_eq!(
+            "line 1, column 4: expected a cup label from 1 to 5 that is not used yet, found \"2\"",
+            error("12324")
+        );
+        assert_eq!(
+            "line 1, column 5: expected a cup label from 1 to 5 that is not used yet, found \"6\"",
+            error("12346")
+        );
+        assert_eq!(
+            "line 1, column 1: expected a cup label from 1 to 5 that is not used yet, found \"0\"",
+            error("01234")
+        );
+    }
+}
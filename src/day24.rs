@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use crate::automaton::{Automaton, HexLattice, Rule};
+use crate::error::ParseResult;
+use crate::hex::{Hex, OffsetLayout, PointyDirection};
+use crate::visualization::{Color, Frame, FrameSink, NoFrames};
+use crate::Solution;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    // The black tiles
+    type Input = HashSet<Hex>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &[String]) -> ParseResult<HashSet<Hex>> {
+        get_initial_state(input)
+    }
+
+    fn part1(black_tiles: &HashSet<Hex>) -> u32 {
+        black_tiles.len() as u32
+    }
+
+    fn part2(initial_state: &HashSet<Hex>) -> u32 {
+        simulate(initial_state, 100, &mut NoFrames)
+    }
+
+    fn visualize(initial_state: &HashSet<Hex>, part: u8, sink: &mut dyn FrameSink) -> bool {
+        // The first part only shows the tiles flipped by the instructions
+        let days = if part == 1 { 0 } else { 100 };
+        simulate(initial_state, days, sink);
+        true
+    }
+}
+
+/*
+ * Flips the tiles according to the daily rules and returns the number of black tiles after
+ * the given number of days.
+ */
+fn simulate(initial_state: &HashSet<Hex>, days: u32, sink: &mut dyn FrameSink) -> u32 {
+    // A black tile with zero or more than two black neighbours is flipped to white, and a
+    // white tile with exactly two black neighbours is flipped to black
+    let mut automaton = Automaton::new(HexLattice, Rule::parse("B2/S12").unwrap(), initial_state.iter().copied());
+    if sink.enabled() {
+        sink.emit(tiles_to_frame(automaton.live_cells(), 0));
+    }
+
+    for day in 1..=days {
+        automaton.step();
+        if sink.enabled() {
+            sink.emit(tiles_to_frame(automaton.live_cells(), day));
+        }
+    }
+
+    automaton.population() as u32
+}
+
+/*
+ * Draws every hex as two cells side by side, so that the rows can be offset by half a hex
+ * from each other.
+ */
+fn tiles_to_frame(black_tiles: &HashSet<Hex>, day: u32) -> Frame {
+    // The doubled column and the row of a hex, with the odd rows shoved right
+    let position = |hex: &Hex| {
+        let (col, row) = hex.to_offset(OffsetLayout::OddR);
+        (2 * col + (row & 1), row)
+    };
+    let min_col = black_tiles.iter().map(|h| position(h).0).min().unwrap_or(0);
+    let max_col = black_tiles.iter().map(|h| position(h).0).max().unwrap_or(0);
+    let min_row = black_tiles.iter().map(|h| position(h).1).min().unwrap_or(0);
+    let max_row = black_tiles.iter().map(|h| position(h).1).max().unwrap_or(0);
+
+    let caption = format!("Day {}: {} black tiles", day, black_tiles.len());
+    let width = (max_col - min_col + 2) as usize;
+    let height = (max_row - min_row + 1) as usize;
+    let mut frame = Frame::new(&caption, width, height, Color::rgb(235, 235, 225));
+    for hex in black_tiles {
+        let (col, row) = position(hex);
+        let (x, y) = ((col - min_col) as usize, (row - min_row) as usize);
+        frame.set(x, y, Color::rgb(20, 20, 30));
+        frame.set(x + 1, y, Color::rgb(20, 20, 30));
+    }
+    frame
+}
+
+fn get_initial_state(input: &[String]) -> ParseResult<HashSet<Hex>> {
+    let mut flipped_tiles: HashSet<Hex> = HashSet::new();
+    for (row, r) in input.iter().enumerate() {
+        // Every path starts from the reference tile
+        let target_hex = PointyDirection::parse_path(r, row)?
+            .into_iter()
+            .fold(Hex::ORIGIN, |hex, direction| hex.neighbour(direction));
+        if flipped_tiles.contains(&target_hex) {
+            flipped_tiles.remove(&target_hex);
+        } else {
+            flipped_tiles.insert(target_hex);
+        }
+    }
+    Ok(flipped_tiles)
+}
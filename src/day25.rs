@@ -0,0 +1,37 @@
+use crate::error::{parse_token, row_at, ParseResult};
+use crate::number_theory::{discrete_log, mod_pow};
+use crate::Solution;
+
+pub struct Day25;
+
+const SUBJECT: u64 = 7;
+const MODULUS: u64 = 20201227;
+
+impl Solution for Day25 {
+    // The public keys of the card and the door
+    type Input = (u64, u64);
+    type Output1 = Option<u64>;
+    type Output2 = Option<u64>;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &[String]) -> ParseResult<(u64, u64)> {
+        let card = row_at(input, 0, "the public key of the card")?;
+        let door = row_at(input, 1, "the public key of the door")?;
+        Ok((
+            parse_token(card, 0, 0, "the public key of the card")?,
+            parse_token(door, 1, 0, "the public key of the door")?,
+        ))
+    }
+
+    fn part1(&(card_key, door_key): &(u64, u64)) -> Option<u64> {
+        // The public key is the subject number 7 transformed loop size times
+        let card_loop_size = discrete_log(SUBJECT, card_key, MODULUS)?;
+        Some(mod_pow(door_key, card_loop_size, MODULUS))
+    }
+
+    fn part2(_: &(u64, u64)) -> Option<u64> {
+        // The last day only has one puzzle
+        None
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/*
+ * Describes malformed input: where in the input the problem was found, what was expected
+ * there and what was found instead.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // The input file, if known. Parsers work on rows, so the file is attached by the caller.
+    pub file: Option<String>,
+    // 1-based line number
+    pub line: usize,
+    // 1-based column number, counted in characters
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /*
+     * Creates an error for the given 0-based row and column indices of the input. They
+     * are reported as 1-based line and column numbers.
+     */
+    pub fn new(row: usize, col: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line: row + 1,
+            column: col + 1,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /*
+     * Creates an error for a row or section that ended before an expected token.
+     */
+    pub fn end_of_input(row: usize, col: usize, expected: &str) -> ParseError {
+        ParseError::new(row, col, expected, "")
+    }
+
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        if self.found.is_empty() {
+            write!(f, "expected {}, found end of input", self.expected)
+        } else {
+            write!(f, "expected {}, found \"{}\"", self.expected, self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/*
+ * Parses a token found at the given 0-based row and column of the input.
+ */
+pub fn parse_token<T: FromStr>(token: &str, row: usize, col: usize, expected: &str) -> ParseResult<T> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(row, col, expected, token))
+}
+
+/*
+ * Returns the 0-based character column at which `token` starts in `row`. The token must
+ * be a subslice of the row, e.g. a result of splitting or trimming it.
+ */
+pub fn column_of(row: &str, token: &str) -> usize {
+    let byte_offset = token.as_ptr() as usize - row.as_ptr() as usize;
+    row[..byte_offset].chars().count()
+}
+
+/*
+ * Returns the row at the given 0-based index, or an error if the input ends before it.
+ */
+pub fn row_at<'a>(input: &'a [String], row: usize, expected: &str) -> ParseResult<&'a str> {
+    input
+        .get(row)
+        .map(String::as_str)
+        .ok_or_else(|| ParseError::end_of_input(row, 0, expected))
+}
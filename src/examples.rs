@@ -0,0 +1,44 @@
+/*
+ * Test cases for the example fixtures in input/examples. Each fixture is an input
+ * `dayNN.txt` or `dayNN_<name>.txt` and its expected answers in a `.answers` file with
+ * the same name, in the format of the known answers file. The build script generates a
+ * test that calls `check_example` for every fixture. Settings that differ between the
+ * examples and the puzzle, like the preamble length of day 9, are given in the input.
+ */
+use std::path::PathBuf;
+
+use crate::answers::KnownAnswers;
+use crate::get_solver;
+use crate::helpers::input_helpers;
+
+fn check_example(name: &str) {
+    let examples_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input").join("examples");
+    let day: u8 = name
+        .get(3..5)
+        .filter(|_| name.starts_with("day"))
+        .and_then(|d| d.parse().ok())
+        .unwrap_or_else(|| panic!("{} is not named after a day", name));
+    let solver = get_solver(day).unwrap_or_else(|| panic!("Day {} has not been implemented", day));
+
+    let input_path = examples_dir.join(format!("{}.txt", name));
+    let answers_path = examples_dir.join(format!("{}.answers", name));
+    let input = input_helpers::read_input_file_text(&input_path).unwrap();
+    let answer_rows = input_helpers::read_input_file(&answers_path)
+        .unwrap_or_else(|e| panic!("Could not read the answers of {}: {}", name, e));
+    let answers = KnownAnswers::parse(&answer_rows)
+        .unwrap_or_else(|e| panic!("{}", e.in_file(&answers_path.display().to_string())));
+
+    let mut checked_parts = 0;
+    for part in 1..=solver.parts {
+        if let Some(expected) = answers.get(day, part) {
+            let answer = solver
+                .solve(&input, part)
+                .unwrap_or_else(|e| panic!("{}", e.in_file(&input_path.display().to_string())));
+            assert_eq!(expected, answer.to_string(), "{} part {}", name, part);
+            checked_parts += 1;
+        }
+    }
+    assert!(checked_parts > 0, "{} has no answers for day {}", name, day);
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
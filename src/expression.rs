@@ -0,0 +1,347 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+}
+
+impl Operator {
+    pub const ALL: [Operator; 6] = [
+        Operator::Add,
+        Operator::Subtract,
+        Operator::Multiply,
+        Operator::Divide,
+        Operator::Remainder,
+        Operator::Power,
+    ];
+
+    pub fn from_symbol(symbol: char) -> Option<Operator> {
+        Operator::ALL.iter().copied().find(|op| op.symbol() == symbol)
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Subtract => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+            Operator::Remainder => '%',
+            Operator::Power => '^',
+        }
+    }
+
+    /*
+     * Applies the operator, or returns None if the result overflows or is undefined, like
+     * a division by zero or a negative exponent.
+     */
+    pub fn apply(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Subtract => lhs.checked_sub(rhs),
+            Operator::Multiply => lhs.checked_mul(rhs),
+            Operator::Divide => lhs.checked_div(rhs),
+            Operator::Remainder => lhs.checked_rem(rhs),
+            Operator::Power => u32::try_from(rhs).ok().and_then(|exp| lhs.checked_pow(exp)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    // a - b - c is (a - b) - c
+    Left,
+    // a ^ b ^ c is a ^ (b ^ c)
+    Right,
+}
+
+/*
+ * The precedence and associativity of the operators an expression may use. Operators
+ * with a higher precedence bind tighter, and operators missing from the table are
+ * rejected by the parser.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperatorTable {
+    // Indexed by the position of the operator in Operator::ALL
+    entries: [Option<(u8, Associativity)>; 6],
+}
+
+impl OperatorTable {
+    pub fn new(entries: &[(Operator, u8, Associativity)]) -> OperatorTable {
+        let mut table = OperatorTable { entries: [None; 6] };
+        for (op, precedence, associativity) in entries {
+            table.entries[*op as usize] = Some((*precedence, *associativity));
+        }
+        table
+    }
+
+    /*
+     * Every operator has the same precedence, so expressions are evaluated from left to
+     * right.
+     */
+    pub fn left_to_right() -> OperatorTable {
+        let entries: Vec<(Operator, u8, Associativity)> =
+            Operator::ALL.iter().map(|op| (*op, 1, Associativity::Left)).collect();
+        OperatorTable::new(&entries)
+    }
+
+    /*
+     * Addition and subtraction bind tighter than multiplication, division and remainder.
+     */
+    pub fn addition_first() -> OperatorTable {
+        use Associativity::*;
+        OperatorTable::new(&[
+            (Operator::Add, 2, Left),
+            (Operator::Subtract, 2, Left),
+            (Operator::Multiply, 1, Left),
+            (Operator::Divide, 1, Left),
+            (Operator::Remainder, 1, Left),
+            (Operator::Power, 3, Right),
+        ])
+    }
+
+    /*
+     * The precedence used in mathematics: powers, then multiplication, division and
+     * remainder, then addition and subtraction.
+     */
+    pub fn standard() -> OperatorTable {
+        use Associativity::*;
+        OperatorTable::new(&[
+            (Operator::Add, 1, Left),
+            (Operator::Subtract, 1, Left),
+            (Operator::Multiply, 2, Left),
+            (Operator::Divide, 2, Left),
+            (Operator::Remainder, 2, Left),
+            (Operator::Power, 3, Right),
+        ])
+    }
+
+    pub fn get(&self, op: Operator) -> Option<(u8, Associativity)> {
+        self.entries[op as usize]
+    }
+
+    /*
+     * Parses the expression on the row at the given 0-based index.
+     */
+    pub fn parse(&self, input: &str, row: usize) -> ParseResult<Expr> {
+        let mut parser = Parser {
+            table: self,
+            tokens: tokenize(input, row)?,
+            pos: 0,
+            row,
+            end: input.chars().count(),
+        };
+        let expr = parser.parse_expression(0)?;
+        match parser.tokens.get(parser.pos) {
+            Some(token) => Err(ParseError::new(row, token.col, "an operator", &token.text)),
+            None => Ok(expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(i64),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /*
+     * Returns the value of the expression, or None if any operation overflows or is
+     * undefined.
+     */
+    pub fn evaluate(&self) -> Option<i64> {
+        match self {
+            Expr::Number(value) => Some(*value),
+            Expr::Binary(op, lhs, rhs) => op.apply(lhs.evaluate()?, rhs.evaluate()?),
+        }
+    }
+}
+
+// Every operation is wrapped in parentheses, so the structure of the tree is visible
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(value) => write!(f, "{}", value),
+            Expr::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op.symbol(), rhs),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Number(i64),
+    Operator(Operator),
+    Open,
+    Close,
+}
+
+struct Token {
+    kind: TokenKind,
+    // The 0-based column of the first character
+    col: usize,
+    text: String,
+}
+
+fn tokenize(input: &str, row: usize) -> ParseResult<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut col = 0;
+    while col < chars.len() {
+        let c = chars[col];
+        let len = match c {
+            ' ' => {
+                col += 1;
+                continue;
+            }
+            '0'..='9' => chars[col..].iter().take_while(|c| c.is_ascii_digit()).count(),
+            _ => 1,
+        };
+        let text: String = chars[col..col + len].iter().collect();
+        let kind = match c {
+            '(' => TokenKind::Open,
+            ')' => TokenKind::Close,
+            '0'..='9' => match text.parse() {
+                Ok(value) => TokenKind::Number(value),
+                Err(_) => return Err(ParseError::new(row, col, "a number that fits in an i64", &text)),
+            },
+            _ => match Operator::from_symbol(c) {
+                Some(op) => TokenKind::Operator(op),
+                None => return Err(ParseError::new(row, col, "a number, an operator or a parenthesis", &text)),
+            },
+        };
+        tokens.push(Token { kind, col, text });
+        col += len;
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    table: &'a OperatorTable,
+    tokens: Vec<Token>,
+    pos: usize,
+    row: usize,
+    // The column right after the input, for errors at the end of the input
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    /*
+     * Parses an expression that only contains operators with at least the given
+     * precedence outside of parentheses.
+     */
+    fn parse_expression(&mut self, min_precedence: u16) -> ParseResult<Expr> {
+        let mut lhs = self.parse_operand()?;
+        while let Some(token) = self.tokens.get(self.pos) {
+            let op = match token.kind {
+                TokenKind::Operator(op) => op,
+                _ => break,
+            };
+            let (precedence, associativity) = match self.table.get(op) {
+                Some(entry) => entry,
+                None => return Err(ParseError::new(self.row, token.col, "an operator of the table", &token.text)),
+            };
+            if (precedence as u16) < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            // The right operand of a left-associative operator stops at the next operator
+            // of the same precedence
+            let rhs = match associativity {
+                Associativity::Left => self.parse_expression(precedence as u16 + 1)?,
+                Associativity::Right => self.parse_expression(precedence as u16)?,
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_operand(&mut self) -> ParseResult<Expr> {
+        let token = match self.tokens.get(self.pos) {
+            Some(token) => token,
+            None => return Err(ParseError::end_of_input(self.row, self.end, "a number or '('")),
+        };
+        self.pos += 1;
+        match token.kind {
+            TokenKind::Number(value) => Ok(Expr::Number(value)),
+            TokenKind::Open => {
+                let expr = self.parse_expression(0)?;
+                match self.tokens.get(self.pos) {
+                    Some(Token { kind: TokenKind::Close, .. }) => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    Some(token) => Err(ParseError::new(self.row, token.col, "an operator or ')'", &token.text)),
+                    None => Err(ParseError::end_of_input(self.row, self.end, "')'")),
+                }
+            }
+            _ => Err(ParseError::new(self.row, token.col, "a number or '('", &token.text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Associativity, Operator, OperatorTable};
+
+    fn evaluate(table: &OperatorTable, input: &str) -> Option<i64> {
+        table.parse(input, 0).unwrap().evaluate()
+    }
+
+    #[test]
+    fn tables_set_the_precedence() {
+        let input = "2 * 3 + 4 ^ 2 ^ 3 - 10 / 3";
+        assert_eq!(
+            "((((((2 * 3) + 4) ^ 2) ^ 3) - 10) / 3)",
+            OperatorTable::left_to_right().parse(input, 0).unwrap().to_string()
+        );
+        assert_eq!(
+            "((2 * ((3 + (4 ^ (2 ^ 3))) - 10)) / 3)",
+            OperatorTable::addition_first().parse(input, 0).unwrap().to_string()
+        );
+        assert_eq!(
+            "(((2 * 3) + (4 ^ (2 ^ 3))) - (10 / 3))",
+            OperatorTable::standard().parse(input, 0).unwrap().to_string()
+        );
+
+        assert_eq!(Some(26), evaluate(&OperatorTable::left_to_right(), "2 * 3 + (4 * 5)"));
+        assert_eq!(Some(46), evaluate(&OperatorTable::addition_first(), "2 * 3 + (4 * 5)"));
+        assert_eq!(Some(1), evaluate(&OperatorTable::standard(), "17 % 4 ^ 2"));
+        assert_eq!(Some(-5), evaluate(&OperatorTable::standard(), "1 - 2 * 3"));
+    }
+
+    #[test]
+    fn evaluation_reports_undefined_results() {
+        let table = OperatorTable::standard();
+        assert_eq!(None, evaluate(&table, "1 / (2 - 2)"));
+        assert_eq!(None, evaluate(&table, "2 ^ 64"));
+        assert_eq!(None, evaluate(&table, "2 ^ (0 - 1)"));
+        assert_eq!(Some(i64::MAX), evaluate(&table, "9223372036854775807"));
+    }
+
+    #[test]
+    fn parse_errors_report_position() {
+        let table = OperatorTable::new(&[(Operator::Add, 1, Associativity::Left)]);
+        let error = table.parse("1 + 2 * 3", 4).err().unwrap();
+        assert_eq!("line 5, column 7: expected an operator of the table, found \"*\"", error.to_string());
+
+        let table = OperatorTable::standard();
+        let error = table.parse("(1 + 2", 0).err().unwrap();
+        assert_eq!("line 1, column 7: expected ')', found end of input", error.to_string());
+        let error = table.parse("1 + 2)", 0).err().unwrap();
+        assert_eq!((1, 6), (error.line, error.column));
+        let error = table.parse("1 2", 0).err().unwrap();
+        assert_eq!((1, 3), (error.line, error.column));
+        let error = table.parse("1 + * 2", 0).err().unwrap();
+        assert_eq!((1, 5), (error.line, error.column));
+        let error = table.parse("1 + x", 0).err().unwrap();
+        assert_eq!((1, 5), (error.line, error.column));
+    }
+}
@@ -0,0 +1,381 @@
+use std::fmt;
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{ParseError, ParseResult};
+
+// The offsets (dx, dy) to the orthogonal neighbours of a cell, y growing downwards
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+// The offsets to the orthogonal and diagonal neighbours of a cell
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/*
+ * A rectangular grid of cells, addressed by (x, y) positions with the origin in the top
+ * left corner.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // The cells row by row
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /*
+     * Builds a grid from its rows. Panics if the rows are not all equally long.
+     */
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        assert!(rows.iter().all(|r| r.len() == width), "All the rows of a grid must be equally long");
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /*
+     * Builds a grid by calling `cell` for every position, row by row.
+     */
+    pub fn from_fn<F>(width: usize, height: usize, cell: F) -> Grid<T>
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(cell)
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /*
+     * Parses a character map with one row of the grid per input row. `parse_cell` gets
+     * each character with its row and column.
+     */
+    pub fn parse<F>(input: &[String], parse_cell: F) -> ParseResult<Grid<T>>
+    where
+        F: Fn(char, usize, usize) -> ParseResult<T>,
+    {
+        let width = input.first().map_or(0, |r| r.chars().count());
+        if width == 0 {
+            return Err(ParseError::end_of_input(0, 0, "a row of cells"));
+        }
+
+        let mut cells = Vec::with_capacity(width * input.len());
+        for (row_idx, row) in input.iter().enumerate() {
+            let mut row_width = 0;
+            for (col_idx, c) in row.chars().enumerate() {
+                if col_idx == width {
+                    return Err(ParseError::new(row_idx, col_idx, "end of the row", &c.to_string()));
+                }
+                cells.push(parse_cell(c, row_idx, col_idx)?);
+                row_width += 1;
+            }
+            if row_width < width {
+                return Err(ParseError::end_of_input(row_idx, row_width, &format!("a row of {} cells", width)));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: input.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /*
+     * Returns the cell at the position as if the grid was repeated infinitely in every
+     * direction.
+     */
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /*
+     * Returns the position one step in the direction (dx, dy) from the given one, or None
+     * if it is outside the grid.
+     */
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /*
+     * Returns the positions of the orthogonal neighbours inside the grid.
+     */
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS4.iter().filter_map(move |dir| self.step(pos, *dir))
+    }
+
+    /*
+     * Returns the positions of the orthogonal and diagonal neighbours inside the grid.
+     */
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS8.iter().filter_map(move |dir| self.step(pos, *dir))
+    }
+
+    /*
+     * Returns the positions from the given one in the direction (dx, dy) up to the edge of
+     * the grid, not including the starting position.
+     */
+    pub fn ray(&self, pos: (usize, usize), direction: (isize, isize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        assert!(direction != (0, 0), "A ray needs a direction");
+        iter::successors(self.step(pos, direction), move |p| self.step(*p, direction))
+    }
+
+    /*
+     * Returns all the positions row by row.
+     */
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /*
+     * Returns all the cells with their positions, row by row.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /*
+     * Returns the grid rotated and mirrored to the given orientation. Quarter turns swap
+     * the width and the height.
+     */
+    pub fn orient(&self, orientation: Orientation) -> Grid<T> {
+        let (width, height) = match orientation.quarter_turns % 2 {
+            0 => (self.width, self.height),
+            _ => (self.height, self.width),
+        };
+        Grid::from_fn(width, height, |(x, y)| {
+            let (src_x, src_y) = orientation.source(x, y, width, height);
+            self.cells[src_y * self.width + src_x].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("<{},{}> is outside the {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("<{},{}> is outside the {}x{} grid", x, y, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/*
+ * One of the eight ways to rotate and mirror a grid: a number of counterclockwise quarter
+ * turns, optionally combined with a mirroring.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub flipped: bool,
+}
+
+impl Orientation {
+    pub fn all() -> [Orientation; 8] {
+        let mut orientations = [Orientation {
+            quarter_turns: 0,
+            flipped: false,
+        }; 8];
+        for (idx, orientation) in orientations.iter_mut().enumerate() {
+            orientation.quarter_turns = idx as u8 % 4;
+            orientation.flipped = idx >= 4;
+        }
+        orientations
+    }
+
+    /*
+     * Returns the position in the original grid of the cell that ends up at (x, y) in the
+     * oriented grid of the given size.
+     */
+    fn source(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let (nx, ny) = (width - 1 - x, height - 1 - y);
+        match (self.quarter_turns % 4, self.flipped) {
+            (0, false) => (x, y),
+            (1, false) => (ny, x),
+            (2, false) => (nx, ny),
+            (3, false) => (y, nx),
+            (0, true) => (nx, y),
+            (1, true) => (ny, nx),
+            (2, true) => (x, ny),
+            _ => (y, x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Grid, Orientation};
+    use crate::error::{ParseError, ParseResult};
+
+    fn parse_digit(c: char, row: usize, col: usize) -> ParseResult<u32> {
+        c.to_digit(10)
+            .ok_or_else(|| ParseError::new(row, col, "a digit", &c.to_string()))
+    }
+
+    fn rows(input: &[&str]) -> Vec<String> {
+        input.iter().map(|r| r.to_string()).collect()
+    }
+
+    fn example() -> Grid<u32> {
+        Grid::parse(&rows(&["123", "456"]), parse_digit).unwrap()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = example();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(vec![2, 5], grid.column(1).copied().collect::<Vec<u32>>());
+        assert_eq!("123\n456\n", grid.to_string());
+
+        let error = Grid::parse(&rows(&["123", "4x6"]), parse_digit).unwrap_err();
+        assert_eq!((2, 2, "x"), (error.line, error.column, error.found.as_str()));
+        let error = Grid::parse(&rows(&["123", "45"]), parse_digit).unwrap_err();
+        assert_eq!("line 2, column 3: expected a row of 3 cells, found end of input", error.to_string());
+        let error = Grid::parse(&rows(&["123", "4567"]), parse_digit).unwrap_err();
+        assert_eq!((2, 4, "7"), (error.line, error.column, error.found.as_str()));
+    }
+
+    #[test]
+    fn wrapping_access_repeats_the_grid() {
+        let grid = example();
+        assert_eq!(1, *grid.get_wrapping(3, 2));
+        assert_eq!(6, *grid.get_wrapping(-1, -1));
+        assert_eq!(4, *grid.get_wrapping(9, 7));
+    }
+
+    #[test]
+    fn neighbours_and_rays_stay_inside() {
+        let grid = example();
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours4((0, 0)).collect::<Vec<_>>());
+        assert_eq!(5, grid.neighbours8((1, 0)).count());
+        assert_eq!(vec![(1, 1)], grid.ray((0, 0), (1, 1)).collect::<Vec<_>>());
+        assert_eq!(vec![(1, 0), (0, 0)], grid.ray((2, 0), (-1, 0)).collect::<Vec<_>>());
+        assert_eq!(0, grid.ray((2, 1), (0, 1)).count());
+    }
+
+    #[test]
+    fn orientations_are_distinct() {
+        let grid = example();
+        let turned = grid.orient(Orientation {
+            quarter_turns: 1,
+            flipped: false,
+        });
+        assert_eq!("36\n25\n14\n", turned.to_string());
+
+        let flipped = grid.orient(Orientation {
+            quarter_turns: 0,
+            flipped: true,
+        });
+        assert_eq!("321\n654\n", flipped.to_string());
+
+        let oriented: HashSet<Grid<u32>> = Orientation::all().iter().map(|o| grid.orient(*o)).collect();
+        assert_eq!(8, oriented.len());
+    }
+}
@@ -0,0 +1,159 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use flate2::read::MultiGzDecoder;
+
+// The input name that reads the input from the standard input
+pub const STDIN: &str = "-";
+// Overrides the default `input` directory for relative input names
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/*
+ * Reads the rows of a named input, e.g. "day07.txt". See `input_path` for how the name
+ * is resolved.
+ */
+pub fn read_input(input_name: &str) -> Result<Vec<String>, io::Error> {
+    read_input_file(input_path(input_name))
+}
+
+/*
+ * Reads a named input as a single string, for parsers that do not work row by row.
+ */
+pub fn read_input_text(input_name: &str) -> Result<String, io::Error> {
+    read_input_file_text(input_path(input_name))
+}
+
+pub fn read_input_file<P: AsRef<Path>>(input_path: P) -> Result<Vec<String>, io::Error> {
+    Ok(read_input_file_text(input_path)?
+        .lines()
+        .map(str::to_string)
+        .collect())
+}
+
+/*
+ * Reads the whole input from the given path, or from stdin if the path is "-". Gzip
+ * compressed contents are decompressed regardless of the file extension.
+ */
+pub fn read_input_file_text<P: AsRef<Path>>(input_path: P) -> Result<String, io::Error> {
+    let input_path = input_path.as_ref();
+    let mut bytes = Vec::new();
+    if input_path == Path::new(STDIN) {
+        io::stdin().read_to_end(&mut bytes)?;
+    } else {
+        File::open(input_path)?.read_to_end(&mut bytes)?;
+    }
+
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut text = String::new();
+        MultiGzDecoder::new(&bytes[..]).read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/*
+ * Resolves the path of a named input. "-" stands for stdin and absolute paths are used
+ * as they are. Other names are relative to the directory in `AOC_INPUT_DIR`, or to
+ * `input` if it is not set. If the file does not exist but a gzip compressed version of
+ * it (with a `.gz` suffix) does, the compressed file is used instead.
+ */
+pub fn input_path(input_name: &str) -> PathBuf {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    input_path_in(input_dir.as_deref(), input_name)
+}
+
+/*
+ * Resolves the path of a named input like `input_path`, with relative names under the
+ * given directory instead of the one in `AOC_INPUT_DIR`, or under `input` if it is None.
+ */
+pub fn input_path_in(input_dir: Option<&Path>, input_name: &str) -> PathBuf {
+    if input_name == STDIN {
+        return PathBuf::from(STDIN);
+    }
+
+    let path = Path::new(input_name);
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        input_dir.unwrap_or_else(|| Path::new(DEFAULT_INPUT_DIR)).join(path)
+    };
+
+    let mut compressed = path.clone().into_os_string();
+    compressed.push(".gz");
+    let compressed = PathBuf::from(compressed);
+    if !path.exists() && compressed.exists() {
+        compressed
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::{input_path, input_path_in, read_input_file, read_input_file_text};
+
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("aoc-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn reads_rows_of_plain_input() {
+        let path = temp_file("plain.txt", b"1-3 a: abcde\r\n2-9 c: ccccccccc\n");
+        assert_eq!(
+            vec!["1-3 a: abcde".to_string(), "2-9 c: ccccccccc".to_string()],
+            read_input_file(&path).unwrap()
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reads_gzip_input_transparently() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"first\nsecond\n").unwrap();
+        let path = temp_file("compressed.txt.gz", &encoder.finish().unwrap());
+
+        assert_eq!("first\nsecond\n", read_input_file_text(&path).unwrap());
+        // The suffix can be left out of the name
+        let uncompressed_name = path.to_str().unwrap().trim_end_matches(".gz");
+        assert_eq!(path, input_path(uncompressed_name));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn relative_names_are_resolved_under_the_input_dir() {
+        let input_dir = env::temp_dir().join(format!("aoc-{}-input-dir", std::process::id()));
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("day02.txt.gz"), b"").unwrap();
+
+        let day01 = input_path_in(Some(&input_dir), "day01.txt");
+        let day02 = input_path_in(Some(&input_dir), "day02.txt");
+        fs::remove_dir_all(&input_dir).unwrap();
+        assert_eq!(input_dir.join("day01.txt"), day01);
+        assert_eq!(input_dir.join("day02.txt.gz"), day02);
+
+        let missing = "aoc-no-such-input.txt";
+        assert_eq!(std::path::Path::new("input").join(missing), input_path_in(None, missing));
+    }
+
+    #[test]
+    fn absolute_paths_and_stdin_are_not_prefixed() {
+        let absolute = env::temp_dir().join("day01.txt");
+        assert_eq!(absolute, input_path(absolute.to_str().unwrap()));
+        assert_eq!(std::path::PathBuf::from("-"), input_path("-"));
+    }
+}
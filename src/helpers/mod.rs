@@ -1 +1,2 @@
 pub mod input_helpers;
+pub mod records;
@@ -0,0 +1,166 @@
+use crate::error::{column_of, ParseError, ParseResult};
+
+/*
+ * A run of rows between blank lines, e.g. a passport or a group of answers.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    // The 0-based index of the first row in the whole input
+    pub start: usize,
+    pub rows: &'a [String],
+}
+
+impl<'a> Section<'a> {
+    // The 0-based index of the row after the section
+    pub fn end(&self) -> usize {
+        self.start + self.rows.len()
+    }
+
+    /*
+     * Checks that the first row is the given header, like "Player 1:", and returns the
+     * rest of the section.
+     */
+    pub fn after_header(&self, header: &str) -> ParseResult<Section<'a>> {
+        match self.rows.first() {
+            Some(row) if row == header => Ok(Section {
+                start: self.start + 1,
+                rows: &self.rows[1..],
+            }),
+            Some(row) => Err(ParseError::new(self.start, 0, &format!("\"{}\"", header), row)),
+            None => Err(ParseError::end_of_input(self.start, 0, &format!("\"{}\"", header))),
+        }
+    }
+
+    /*
+     * Returns the rows with their 0-based indices in the whole input, for error
+     * positions.
+     */
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        let start = self.start;
+        self.rows
+            .iter()
+            .enumerate()
+            .map(move |(offset, row)| (start + offset, row.as_str()))
+    }
+
+    /*
+     * Returns the whitespace separated records on all rows, with their 0-based row and
+     * column.
+     */
+    pub fn records(&self) -> impl Iterator<Item = (usize, usize, &'a str)> + '_ {
+        self.lines().flat_map(|(row_idx, row)| {
+            row.split_whitespace()
+                .map(move |record| (row_idx, column_of(row, record), record))
+        })
+    }
+}
+
+/*
+ * Iterates over the blank-line separated sections of the input. Any number of blank
+ * lines (or lines with only whitespace) separate two sections, and blank lines at the
+ * start or the end of the input do not produce empty sections.
+ */
+pub struct Sections<'a> {
+    input: &'a [String],
+    pos: usize,
+}
+
+impl<'a> Sections<'a> {
+    /*
+     * Returns the next section, or an error at the end of the input describing the
+     * missing section.
+     */
+    pub fn next_section(&mut self, expected: &str) -> ParseResult<Section<'a>> {
+        self.next()
+            .ok_or_else(|| ParseError::end_of_input(self.input.len(), 0, expected))
+    }
+
+    /*
+     * Returns an error if there are sections left, like a third player in a game of two.
+     */
+    pub fn expect_end(&mut self) -> ParseResult<()> {
+        match self.next() {
+            Some(section) => Err(ParseError::new(section.start, 0, "end of input", &section.rows[0])),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Section<'a>> {
+        while self.pos < self.input.len() && is_blank(&self.input[self.pos]) {
+            self.pos += 1;
+        }
+        if self.pos == self.input.len() {
+            return None;
+        }
+        let start = self.pos;
+        while self.pos < self.input.len() && !is_blank(&self.input[self.pos]) {
+            self.pos += 1;
+        }
+        Some(Section {
+            start,
+            rows: &self.input[start..self.pos],
+        })
+    }
+}
+
+pub fn sections(input: &[String]) -> Sections<'_> {
+    Sections { input, pos: 0 }
+}
+
+fn is_blank(row: &str) -> bool {
+    row.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sections;
+
+    fn rows(text: &str) -> Vec<String> {
+        text.split('\n').map(str::to_string).collect()
+    }
+
+    #[test]
+    fn sections_are_separated_by_blank_lines() {
+        let input = rows("\na b\nc\n\n  \nd\n\n");
+        let spans: Vec<(usize, usize)> = sections(&input).map(|s| (s.start, s.end())).collect();
+        assert_eq!(vec![(1, 3), (5, 6)], spans);
+
+        // The last section does not need a blank line after it
+        let input = rows("a\n\nb:1 c:2\nd:3");
+        let last = sections(&input).last().unwrap();
+        assert_eq!(vec![(2, "b:1 c:2"), (3, "d:3")], last.lines().collect::<Vec<(usize, &str)>>());
+        let records: Vec<(usize, usize, &str)> = last.records().collect();
+        assert_eq!(vec![(2, 0, "b:1"), (2, 4, "c:2"), (3, 0, "d:3")], records);
+        assert_eq!(0, sections(&rows("\n\n")).count());
+    }
+
+    #[test]
+    fn missing_and_extra_sections_are_errors() {
+        let input = rows("a\n\nb\n\nc");
+        let mut iter = sections(&input);
+        iter.next_section("the first section").unwrap();
+        iter.next_section("the second section").unwrap();
+        let error = iter.expect_end().unwrap_err();
+        assert_eq!("line 5, column 1: expected end of input, found \"c\"", error.to_string());
+
+        let input = rows("a");
+        let mut iter = sections(&input);
+        iter.next().unwrap();
+        let error = iter.next_section("the second section").unwrap_err();
+        assert_eq!("line 2, column 1: expected the second section, found end of input", error.to_string());
+    }
+
+    #[test]
+    fn headers_are_checked() {
+        let input = rows("\nPlayer 1:\n9\n2");
+        let section = sections(&input).next().unwrap();
+        let deck = section.after_header("Player 1:").unwrap();
+        assert_eq!(vec![(2, "9"), (3, "2")], deck.lines().collect::<Vec<(usize, &str)>>());
+        let error = section.after_header("Player 2:").unwrap_err();
+        assert_eq!("line 2, column 1: expected \"Player 2:\", found \"Player 1:\"", error.to_string());
+    }
+}
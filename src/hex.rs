@@ -0,0 +1,378 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::error::{ParseError, ParseResult};
+
+/*
+ * A hex of a hexagonal grid, stored in axial coordinates (q, r). The cube coordinates are
+ * (q, r, s) with q + r + s = 0. Follows https://www.redblobgames.com/grids/hexagons/, with
+ * r growing southwards.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+// The axial offsets to the six neighbours, counterclockwise from the one at q + 1
+const NEIGHBOUR_OFFSETS: [Hex; 6] = [
+    Hex { q: 1, r: 0 },
+    Hex { q: 1, r: -1 },
+    Hex { q: 0, r: -1 },
+    Hex { q: -1, r: 0 },
+    Hex { q: -1, r: 1 },
+    Hex { q: 0, r: 1 },
+];
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn from_axial(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    /*
+     * Panics if the coordinates do not sum up to 0.
+     */
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Hex {
+        assert_eq!(0, q + r + s, "Cube coordinates must sum up to 0");
+        Hex { q, r }
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn axial(&self) -> (i32, i32) {
+        (self.q, self.r)
+    }
+
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn from_offset(layout: OffsetLayout, col: i32, row: i32) -> Hex {
+        match layout {
+            OffsetLayout::OddR => Hex::from_axial(col - (row - (row & 1)) / 2, row),
+            OffsetLayout::EvenR => Hex::from_axial(col - (row + (row & 1)) / 2, row),
+            OffsetLayout::OddQ => Hex::from_axial(col, row - (col - (col & 1)) / 2),
+            OffsetLayout::EvenQ => Hex::from_axial(col, row - (col + (col & 1)) / 2),
+        }
+    }
+
+    /*
+     * Returns the (column, row) position of the hex in the offset layout.
+     */
+    pub fn to_offset(&self, layout: OffsetLayout) -> (i32, i32) {
+        let (q, r) = (self.q, self.r);
+        match layout {
+            OffsetLayout::OddR => (q + (r - (r & 1)) / 2, r),
+            OffsetLayout::EvenR => (q + (r + (r & 1)) / 2, r),
+            OffsetLayout::OddQ => (q, r + (q - (q & 1)) / 2),
+            OffsetLayout::EvenQ => (q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    /*
+     * Returns the number of steps to the origin.
+     */
+    pub fn length(&self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    pub fn distance(&self, other: Hex) -> i32 {
+        (*self - other).length()
+    }
+
+    pub fn neighbour<D: HexDirection>(&self, direction: D) -> Hex {
+        *self + direction.offset()
+    }
+
+    pub fn neighbours(&self) -> [Hex; 6] {
+        let mut neighbours = NEIGHBOUR_OFFSETS;
+        for neighbour in neighbours.iter_mut() {
+            *neighbour = *self + *neighbour;
+        }
+        neighbours
+    }
+
+    /*
+     * Returns the hexes at exactly `radius` steps from this one, going around the ring
+     * counterclockwise.
+     */
+    pub fn ring(&self, radius: u32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = *self + NEIGHBOUR_OFFSETS[4] * radius as i32;
+        for offset in &NEIGHBOUR_OFFSETS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + *offset;
+            }
+        }
+        ring
+    }
+
+    /*
+     * Returns the hexes at most `radius` steps from this one, ring by ring from the
+     * center outwards.
+     */
+    pub fn spiral(&self, radius: u32) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /*
+     * Returns the hexes on the straight line from this hex to the other one, both ends
+     * included.
+     */
+    pub fn line_to(&self, other: Hex) -> Vec<Hex> {
+        let steps = self.distance(other);
+        if steps == 0 {
+            return vec![*self];
+        }
+        // Nudge the line off the edges between hexes so that the rounding is consistent
+        let (q0, r0) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6);
+        let (q1, r1) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6);
+        (0..=steps)
+            .map(|i| {
+                let t = i as f64 / steps as f64;
+                Hex::round(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t)
+            })
+            .collect()
+    }
+
+    /*
+     * Returns the hex that contains the fractional axial coordinates.
+     */
+    pub fn round(q: f64, r: f64) -> Hex {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        // Recompute the coordinate that was rounded the most from the other two
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Hex::from_axial(rq as i32, rr as i32)
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::from_axial(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::from_axial(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::from_axial(self.q * factor, self.r * factor)
+    }
+}
+
+/*
+ * The ways to lay out a hexagonal grid as rows and columns. The R layouts have pointy-top
+ * hexes with every odd or even row shoved right by half a hex, the Q layouts flat-top hexes
+ * with every odd or even column shoved down.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetLayout {
+    OddR,
+    EvenR,
+    OddQ,
+    EvenQ,
+}
+
+pub trait HexDirection: Copy {
+    fn offset(self) -> Hex;
+}
+
+/*
+ * The directions to the neighbours of pointy-top hexes, which are lined up in rows.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointyDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl PointyDirection {
+    pub const ALL: [PointyDirection; 6] = [
+        PointyDirection::East,
+        PointyDirection::NorthEast,
+        PointyDirection::NorthWest,
+        PointyDirection::West,
+        PointyDirection::SouthWest,
+        PointyDirection::SouthEast,
+    ];
+
+    /*
+     * Parses a path of directions written without separators, e.g. "esenee", from the given
+     * input row.
+     */
+    pub fn parse_path(path: &str, row: usize) -> ParseResult<Vec<PointyDirection>> {
+        let chars: Vec<char> = path.chars().collect();
+        let unexpected = |idx: usize, expected: &str| match chars.get(idx) {
+            Some(c) => ParseError::new(row, idx, expected, &c.to_string()),
+            None => ParseError::end_of_input(row, idx, expected),
+        };
+
+        let mut directions = Vec::new();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let direction = match chars[idx] {
+                'e' => PointyDirection::East,
+                'w' => PointyDirection::West,
+                'n' | 's' => {
+                    idx += 1;
+                    match (chars[idx - 1], chars.get(idx)) {
+                        ('n', Some('e')) => PointyDirection::NorthEast,
+                        ('n', Some('w')) => PointyDirection::NorthWest,
+                        ('s', Some('e')) => PointyDirection::SouthEast,
+                        ('s', Some('w')) => PointyDirection::SouthWest,
+                        _ => return Err(unexpected(idx, "'e' or 'w'")),
+                    }
+                }
+                _ => return Err(unexpected(idx, "one of e, se, sw, w, nw or ne")),
+            };
+            directions.push(direction);
+            idx += 1;
+        }
+        Ok(directions)
+    }
+}
+
+impl HexDirection for PointyDirection {
+    fn offset(self) -> Hex {
+        match self {
+            PointyDirection::East => NEIGHBOUR_OFFSETS[0],
+            PointyDirection::NorthEast => NEIGHBOUR_OFFSETS[1],
+            PointyDirection::NorthWest => NEIGHBOUR_OFFSETS[2],
+            PointyDirection::West => NEIGHBOUR_OFFSETS[3],
+            PointyDirection::SouthWest => NEIGHBOUR_OFFSETS[4],
+            PointyDirection::SouthEast => NEIGHBOUR_OFFSETS[5],
+        }
+    }
+}
+
+/*
+ * The directions to the neighbours of flat-top hexes, which are lined up in columns.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlatDirection {
+    SouthEast,
+    NorthEast,
+    North,
+    NorthWest,
+    SouthWest,
+    South,
+}
+
+impl FlatDirection {
+    pub const ALL: [FlatDirection; 6] = [
+        FlatDirection::SouthEast,
+        FlatDirection::NorthEast,
+        FlatDirection::North,
+        FlatDirection::NorthWest,
+        FlatDirection::SouthWest,
+        FlatDirection::South,
+    ];
+}
+
+impl HexDirection for FlatDirection {
+    fn offset(self) -> Hex {
+        match self {
+            FlatDirection::SouthEast => NEIGHBOUR_OFFSETS[0],
+            FlatDirection::NorthEast => NEIGHBOUR_OFFSETS[1],
+            FlatDirection::North => NEIGHBOUR_OFFSETS[2],
+            FlatDirection::NorthWest => NEIGHBOUR_OFFSETS[3],
+            FlatDirection::SouthWest => NEIGHBOUR_OFFSETS[4],
+            FlatDirection::South => NEIGHBOUR_OFFSETS[5],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{FlatDirection, Hex, OffsetLayout, PointyDirection};
+
+    #[test]
+    fn offset_layouts_round_trip() {
+        let layouts = [OffsetLayout::OddR, OffsetLayout::EvenR, OffsetLayout::OddQ, OffsetLayout::EvenQ];
+        for hex in Hex::from_axial(-2, 5).spiral(4) {
+            for layout in &layouts {
+                let (col, row) = hex.to_offset(*layout);
+                assert_eq!(hex, Hex::from_offset(*layout, col, row));
+            }
+            assert_eq!(hex, Hex::from_cube(hex.q, hex.r, hex.s()));
+        }
+
+        // The first odd row is shoved right, so its first hex is below and right of the origin
+        assert_eq!((0, 1), Hex::ORIGIN.neighbour(PointyDirection::SouthEast).to_offset(OffsetLayout::OddR));
+        assert_eq!((1, 0), Hex::ORIGIN.neighbour(FlatDirection::SouthEast).to_offset(OffsetLayout::OddQ));
+    }
+
+    #[test]
+    fn rings_spirals_and_lines() {
+        let center = Hex::from_axial(3, -1);
+        assert_eq!(vec![center], center.ring(0));
+        for radius in 1..5 {
+            let ring = center.ring(radius);
+            assert_eq!(6 * radius as usize, ring.len());
+            assert!(ring.iter().all(|h| h.distance(center) == radius as i32));
+        }
+        let spiral: HashSet<Hex> = center.spiral(3).into_iter().collect();
+        assert_eq!(37, spiral.len());
+
+        let end = Hex::from_axial(-4, 7);
+        let line = center.line_to(end);
+        assert_eq!(center.distance(end) as usize + 1, line.len());
+        assert_eq!((Some(&center), Some(&end)), (line.first(), line.last()));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+    }
+
+    #[test]
+    fn paths_are_parsed_into_directions() {
+        let path = PointyDirection::parse_path("nwwswee", 0).unwrap();
+        assert_eq!(Hex::ORIGIN, path.iter().fold(Hex::ORIGIN, |hex, d| hex.neighbour(*d)));
+        let path = PointyDirection::parse_path("esew", 0).unwrap();
+        assert_eq!(
+            Hex::ORIGIN.neighbour(PointyDirection::SouthEast),
+            path.iter().fold(Hex::ORIGIN, |hex, d| hex.neighbour(*d))
+        );
+
+        let error = PointyDirection::parse_path("enx", 3).unwrap_err();
+        assert_eq!("line 4, column 3: expected 'e' or 'w', found \"x\"", error.to_string());
+        let error = PointyDirection::parse_path("es", 0).unwrap_err();
+        assert_eq!((1, 3, ""), (error.line, error.column, error.found.as_str()));
+        let error = PointyDirection::parse_path("ewq", 0).unwrap_err();
+        assert_eq!((1, 3, "q"), (error.line, error.column, error.found.as_str()));
+    }
+
+    #[test]
+    fn long_paths_do_not_overflow() {
+        let path = "e".repeat(1000);
+        let directions = PointyDirection::parse_path(&path, 0).unwrap();
+        let hex = directions.iter().fold(Hex::ORIGIN, |hex, d| hex.neighbour(*d));
+        assert_eq!(1000, hex.length());
+    }
+}
@@ -0,0 +1,82 @@
+pub mod answers;
+pub mod assignment;
+pub mod automaton;
+pub mod bench;
+mod error;
+#[cfg(test)]
+mod examples;
+pub mod expression;
+pub mod grid;
+pub mod helpers;
+pub mod hex;
+pub mod number_theory;
+pub mod report;
+pub mod runner;
+mod solution;
+pub mod visualization;
+pub mod vm;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub use error::{ParseError, ParseResult};
+pub use solution::{Answer, Solution, Solver, TimedAnswers};
+
+/*
+ * Returns the solver of the given day, or None if the day has not been implemented.
+ */
+pub fn get_solver(day: u8) -> Option<Solver> {
+    let solver = match day {
+        1 => Solver::new::<day01::Day01>(day),
+        2 => Solver::new::<day02::Day02>(day),
+        3 => Solver::new::<day03::Day03>(day),
+        4 => Solver::new::<day04::Day04>(day),
+        5 => Solver::new::<day05::Day05>(day),
+        6 => Solver::new::<day06::Day06>(day),
+        7 => Solver::new::<day07::Day07>(day),
+        8 => Solver::new::<day08::Day08>(day),
+        9 => Solver::new::<day09::Day09>(day),
+        10 => Solver::new::<day10::Day10>(day),
+        11 => Solver::new::<day11::Day11>(day),
+        12 => Solver::new::<day12::Day12>(day),
+        13 => Solver::new::<day13::Day13>(day),
+        14 => Solver::new::<day14::Day14>(day),
+        15 => Solver::new::<day15::Day15>(day),
+        16 => Solver::new::<day16::Day16>(day),
+        17 => Solver::new::<day17::Day17>(day),
+        18 => Solver::new::<day18::Day18>(day),
+        19 => Solver::new::<day19::Day19>(day),
+        20 => Solver::new::<day20::Day20>(day),
+        21 => Solver::new::<day21::Day21>(day),
+        22 => Solver::new::<day22::Day22>(day),
+        23 => Solver::new::<day23::Day23>(day),
+        24 => Solver::new::<day24::Day24>(day),
+        25 => Solver::new::<day25::Day25>(day),
+        _ => return None,
+    };
+
+    Some(solver)
+}
@@ -1,3 +1,585 @@
+use std::env;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use advent_of_code_2020::answers::{KnownAnswers, Verdict, ANSWERS_FILE};
+use advent_of_code_2020::bench::{self, History, DEFAULT_THRESHOLD, HISTORY_FILE};
+use advent_of_code_2020::get_solver;
+use advent_of_code_2020::helpers::input_helpers;
+use advent_of_code_2020::report::{self, OutputFormat, ResultRecord};
+use advent_of_code_2020::runner::{self, DayOutcome};
+use advent_of_code_2020::visualization::{FrameSink, PpmSink, TerminalSink};
+
+const USAGE: &str = "Usage:
+    aoc list
+    aoc run --day <day> [--part <part>] [--input <path>] [--format text|json|csv]
+    aoc run --all [--jobs <threads>] [--timeout <seconds>] [--format text|json|csv]
+    aoc verify [--day <day>] [--answers <path>]
+    aoc bench (--day <day> | --all) [--iterations <count>] [--history <path>] [--threshold <percent>]
+    aoc visualize --day <day> [--part <part>] [--input <path>] [--delay <milliseconds>]
+    aoc visualize --day <day> [--part <part>] [--input <path>] --ppm <directory> [--scale <pixels>]";
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("list") if args.len() == 1 => {
+            list();
+            Ok(())
+        }
+        Some("run") => parse_run_options(&args[1..]).and_then(|options| run(&options)),
+        Some("verify") => parse_verify_options(&args[1..]).and_then(|options| verify(&options)),
+        Some("bench") => parse_bench_options(&args[1..]).and_then(|options| run_benchmarks(&options)),
+        Some("visualize") => parse_visualize_options(&args[1..]).and_then(|options| visualize(&options)),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+fn list() {
+    for day in 1..=25 {
+        if let Some(solver) = get_solver(day) {
+            let parts: Vec<String> = (1..=solver.parts).map(|p| p.to_string()).collect();
+            println!("Day {:02}: parts {}", day, parts.join(", "));
+        }
+    }
+}
+
+struct RunOptions {
+    // None means that all the days are run
+    day: Option<u8>,
+    // None means that all the parts of the selected days are run
+    part: Option<u8>,
+    input_path: Option<String>,
+    format: OutputFormat,
+    // The number of worker threads for running all the days, all the cores if None
+    jobs: Option<usize>,
+    // The maximum time a single day may take in a parallel run
+    timeout: Option<Duration>,
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
+    let mut options = RunOptions {
+        day: None,
+        part: None,
+        input_path: None,
+        format: OutputFormat::Text,
+        jobs: None,
+        timeout: None,
+    };
+    let mut all_days = false;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => all_days = true,
+            "--day" => {
+                options.day = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--format" => {
+                options.format = parse_option_value(arg, args_iter.next())?;
+            }
+            "--jobs" => {
+                options.jobs = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--timeout" => {
+                let seconds: f64 = parse_option_value(arg, args_iter.next())?;
+                if !(seconds > 0.0 && seconds.is_finite()) {
+                    return Err(format!("Invalid value for {}: {}\n\n{}", arg, seconds, USAGE));
+                }
+                options.timeout = Some(Duration::from_secs_f64(seconds));
+            }
+            "--part" => {
+                options.part = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--input" => match args_iter.next() {
+                Some(path) => options.input_path = Some(path.to_string()),
+                None => return Err(format!("Missing value for {}\n\n{}", arg, USAGE)),
+            },
+            _ => return Err(format!("Unexpected argument: {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    if all_days == options.day.is_some() {
+        return Err(format!("Exactly one of --day and --all is required\n\n{}", USAGE));
+    }
+    if all_days && (options.part.is_some() || options.input_path.is_some()) {
+        return Err(format!("--all cannot be combined with --part or --input\n\n{}", USAGE));
+    }
+    if !all_days && (options.jobs.is_some() || options.timeout.is_some()) {
+        return Err(format!("--jobs and --timeout can only be used with --all\n\n{}", USAGE));
+    }
+    if options.jobs == Some(0) {
+        return Err(format!("--jobs must be at least 1\n\n{}", USAGE));
+    }
+
+    Ok(options)
+}
+
+fn parse_option_value<T: std::str::FromStr>(option: &str, value: Option<&String>) -> Result<T, String> {
+    match value {
+        Some(v) => v
+            .parse::<T>()
+            .map_err(|_| format!("Invalid value for {}: {}\n\n{}", option, v, USAGE)),
+        None => Err(format!("Missing value for {}\n\n{}", option, USAGE)),
+    }
+}
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    let day = match options.day {
+        Some(day) => day,
+        // One failing day must not hide the answers of the others
+        None => return run_parallel(&(1..=25).collect::<Vec<u8>>(), options),
+    };
+
+    let records = solve_day(day, options.part, options.input_path.as_deref())?;
+    if options.format == OutputFormat::Text {
+        print_day(day, &records);
+    }
+    print_records(options.format, &records);
+
+    Ok(())
+}
+
+/*
+ * Runs the days on a thread pool. Days that fail, panic or time out are reported after
+ * the answers of the others, and fail the run.
+ */
+fn run_parallel(days: &[u8], options: &RunOptions) -> Result<(), String> {
+    let jobs = match options.jobs {
+        Some(jobs) => jobs,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let reports = runner::run_parallel(days, jobs, options.timeout, |day| solve_day(day, None, None));
+
+    let mut records = Vec::new();
+    let mut problems = Vec::new();
+    for report in reports {
+        match report.outcome {
+            DayOutcome::Solved(day_records) => {
+                if options.format == OutputFormat::Text {
+                    print_day(report.day, &day_records);
+                }
+                records.extend(day_records);
+            }
+            DayOutcome::Failed(message) => problems.push(message),
+            DayOutcome::Panicked(message) => {
+                problems.push(format!("Day {} panicked: {}", report.day, message));
+            }
+            DayOutcome::TimedOut(timeout) => {
+                problems.push(format!("Day {} timed out after {:?}", report.day, timeout));
+            }
+        }
+    }
+    print_records(options.format, &records);
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("\n"))
+    }
+}
+
+/*
+ * Solves the given part of the day, or all the parts if `part` is None.
+ */
+fn solve_day(day: u8, part: Option<u8>, input_path: Option<&str>) -> Result<Vec<ResultRecord>, String> {
+    let solver = get_solver(day).ok_or(format!("Day {} has not been implemented", day))?;
+
+    let (input_name, input) = read_day_input(day, input_path)?;
+
+    let parts: Vec<u8> = match part {
+        Some(part) if (1..=solver.parts).contains(&part) => vec![part],
+        Some(part) => return Err(format!("Day {} has no part {}", day, part)),
+        None => (1..=solver.parts).collect(),
+    };
+
+    let mut records = Vec::new();
+    for part in parts {
+        // The elapsed time covers both parsing the input and solving the part
+        let start = Instant::now();
+        let answer = solver
+            .solve(&input, part)
+            .map_err(|e| e.in_file(&input_name).to_string())?;
+        records.push(ResultRecord {
+            day,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(records)
+}
+
+fn print_day(day: u8, records: &[ResultRecord]) {
+    println!("Day {:02}", day);
+    for record in records {
+        println!("Task {}: {}", record.part, record.answer);
+    }
+}
+
+fn print_records(format: OutputFormat, records: &[ResultRecord]) {
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => println!("{}", report::to_json(records)),
+        OutputFormat::Csv => println!("{}", report::to_csv(records)),
+    }
+}
+
+/*
+ * Reads the input of the given day from `input_path`, or from the default location if
+ * it is not given. Returns the name of the input for diagnostics, and the input itself.
+ */
+fn read_day_input(day: u8, input_path: Option<&str>) -> Result<(String, String), String> {
+    let input_path = match input_path {
+        Some(path) => PathBuf::from(path),
+        None => input_helpers::input_path(&format!("day{:02}.txt", day)),
+    };
+    let input_name = match input_path.to_str() {
+        Some(input_helpers::STDIN) => "<stdin>".to_string(),
+        _ => input_path.display().to_string(),
+    };
+    let input = input_helpers::read_input_file_text(&input_path)
+        .map_err(|e| format!("Could not read the input for day {} from {}: {}", day, input_name, e))?;
+
+    Ok((input_name, input))
+}
+
+struct VerifyOptions {
+    // None means that all the days are verified
+    day: Option<u8>,
+    answers_path: Option<String>,
+}
+
+fn parse_verify_options(args: &[String]) -> Result<VerifyOptions, String> {
+    let mut options = VerifyOptions {
+        day: None,
+        answers_path: None,
+    };
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--day" => {
+                options.day = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--answers" => match args_iter.next() {
+                Some(path) => options.answers_path = Some(path.to_string()),
+                None => return Err(format!("Missing value for {}\n\n{}", arg, USAGE)),
+            },
+            _ => return Err(format!("Unexpected argument: {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(options)
+}
+
+/*
+ * Solves the selected days with the real inputs and compares the answers to the known
+ * ones. Fails if any of the answers is wrong or could not be computed.
+ */
+fn verify(options: &VerifyOptions) -> Result<(), String> {
+    let answers_path = match &options.answers_path {
+        Some(path) => PathBuf::from(path),
+        None => input_helpers::input_path(ANSWERS_FILE),
+    };
+    let answers_name = answers_path.display().to_string();
+    let known_answers = input_helpers::read_input_file(&answers_path)
+        .map_err(|e| format!("Could not read the answers from {}: {}", answers_name, e))
+        .and_then(|rows| KnownAnswers::parse(&rows).map_err(|e| e.in_file(&answers_name).to_string()))?;
+
+    let days: Vec<u8> = match options.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    // Day, part, result, expected and actual answer
+    let mut table: Vec<Vec<String>> = Vec::new();
+    let mut all_passed = true;
+    for day in days {
+        let solver = get_solver(day).ok_or(format!("Day {} has not been implemented", day))?;
+        let input = read_day_input(day, None);
+
+        for part in 1..=solver.parts {
+            let expected = known_answers.get(day, part).unwrap_or("-").to_string();
+            let (result, actual) = match &input {
+                Ok((input_name, input)) => match solver.solve(input, part) {
+                    Ok(answer) => {
+                        let answer = answer.to_string();
+                        let result = match known_answers.check(day, part, &answer) {
+                            Verdict::Pass => "pass",
+                            Verdict::Fail => "FAIL",
+                            Verdict::Missing => "missing",
+                        };
+                        (result, answer)
+                    }
+                    Err(e) => ("ERROR", e.in_file(input_name).to_string()),
+                },
+                Err(message) => ("ERROR", message.to_string()),
+            };
+            all_passed &= result == "pass" || result == "missing";
+            table.push(vec![format!("{:02}", day), part.to_string(), result.to_string(), expected, actual]);
+        }
+    }
+
+    print_table(&["Day", "Part", "Result", "Expected", "Actual"], &table);
+
+    if all_passed {
+        Ok(())
+    } else {
+        Err("Some of the answers did not match the known answers".to_string())
+    }
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    println!("{}", format_row(header.to_vec()));
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+struct BenchOptions {
+    // None means that all the days are benchmarked
+    day: Option<u8>,
+    iterations: u32,
+    history_path: String,
+    // The slowdown in percent that is reported as a regression
+    threshold: f64,
+}
+
+fn parse_bench_options(args: &[String]) -> Result<BenchOptions, String> {
+    let mut options = BenchOptions {
+        day: None,
+        iterations: 10,
+        history_path: HISTORY_FILE.to_string(),
+        threshold: DEFAULT_THRESHOLD,
+    };
+    let mut all_days = false;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => all_days = true,
+            "--day" => {
+                options.day = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--iterations" => {
+                options.iterations = parse_option_value(arg, args_iter.next())?;
+            }
+            "--history" => match args_iter.next() {
+                Some(path) => options.history_path = path.to_string(),
+                None => return Err(format!("Missing value for {}\n\n{}", arg, USAGE)),
+            },
+            "--threshold" => {
+                options.threshold = parse_option_value(arg, args_iter.next())?;
+            }
+            _ => return Err(format!("Unexpected argument: {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    if all_days == options.day.is_some() {
+        return Err(format!("Exactly one of --day and --all is required\n\n{}", USAGE));
+    }
+    if options.iterations == 0 {
+        return Err(format!("--iterations must be at least 1\n\n{}", USAGE));
+    }
+
+    Ok(options)
+}
+
+/*
+ * Benchmarks the selected days, appends the medians to the history file and compares
+ * them to the previous run. Fails if any of the stages regressed.
+ */
+fn run_benchmarks(options: &BenchOptions) -> Result<(), String> {
+    let history = match input_helpers::read_input_file(&options.history_path) {
+        Ok(rows) => History::parse(&rows).map_err(|e| e.in_file(&options.history_path).to_string())?,
+        // There is no history before the first run
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => History::default(),
+        Err(e) => return Err(format!("Could not read the history from {}: {}", options.history_path, e)),
+    };
+
+    let days: Vec<u8> = match options.day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+
+    let mut measurements = Vec::new();
+    for day in days {
+        let solver = get_solver(day).ok_or(format!("Day {} has not been implemented", day))?;
+        let (input_name, input) = read_day_input(day, None)?;
+        measurements.extend(
+            bench::benchmark(&solver, &input, options.iterations)
+                .map_err(|e| e.in_file(&input_name).to_string())?,
+        );
+    }
+
+    let mut table: Vec<Vec<String>> = Vec::new();
+    let mut regressions = 0;
+    for measurement in &measurements {
+        let (previous, change) = match history.previous(measurement.day, measurement.stage) {
+            Some(previous) => {
+                let mut change = format!("{:+.1}%", bench::change_percent(previous, measurement.median()));
+                if bench::is_regression(previous, measurement.median(), options.threshold) {
+                    change.push_str(" REGRESSION");
+                    regressions += 1;
+                }
+                (bench::format_duration(previous), change)
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        table.push(vec![
+            format!("{:02}", measurement.day),
+            measurement.stage.to_string(),
+            bench::format_duration(measurement.min()),
+            bench::format_duration(measurement.median()),
+            bench::format_duration(measurement.mean()),
+            previous,
+            change,
+        ]);
+    }
+    print_table(&["Day", "Stage", "Min", "Median", "Mean", "Previous", "Change"], &table);
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let mut history_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&options.history_path)
+        .map_err(|e| format!("Could not open the history file {}: {}", options.history_path, e))?;
+    for measurement in &measurements {
+        writeln!(history_file, "{}", History::row(timestamp, measurement))
+            .map_err(|e| format!("Could not write the history file {}: {}", options.history_path, e))?;
+    }
+
+    if regressions > 0 {
+        Err(format!(
+            "{} stage(s) were more than {}% slower than in the previous run",
+            regressions, options.threshold
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+struct VisualizeOptions {
+    day: u8,
+    part: u8,
+    input_path: Option<String>,
+    // The frames are written as PPM images to this directory instead of the terminal
+    ppm_dir: Option<String>,
+    // The size of a cell in the PPM images in pixels
+    scale: Option<usize>,
+    // How long each frame is shown in the terminal
+    delay: Option<Duration>,
+}
+
+fn parse_visualize_options(args: &[String]) -> Result<VisualizeOptions, String> {
+    let mut options = VisualizeOptions {
+        day: 0,
+        part: 1,
+        input_path: None,
+        ppm_dir: None,
+        scale: None,
+        delay: None,
+    };
+    let mut day = None;
+
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--day" => {
+                day = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--part" => {
+                options.part = parse_option_value(arg, args_iter.next())?;
+            }
+            "--input" => match args_iter.next() {
+                Some(path) => options.input_path = Some(path.to_string()),
+                None => return Err(format!("Missing value for {}\n\n{}", arg, USAGE)),
+            },
+            "--ppm" => match args_iter.next() {
+                Some(path) => options.ppm_dir = Some(path.to_string()),
+                None => return Err(format!("Missing value for {}\n\n{}", arg, USAGE)),
+            },
+            "--scale" => {
+                options.scale = Some(parse_option_value(arg, args_iter.next())?);
+            }
+            "--delay" => {
+                options.delay = Some(Duration::from_millis(parse_option_value(arg, args_iter.next())?));
+            }
+            _ => return Err(format!("Unexpected argument: {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    options.day = day.ok_or(format!("--day is required\n\n{}", USAGE))?;
+    if options.ppm_dir.is_some() && options.delay.is_some() {
+        return Err(format!("--delay cannot be combined with --ppm\n\n{}", USAGE));
+    }
+    if options.ppm_dir.is_none() && options.scale.is_some() {
+        return Err(format!("--scale can only be used with --ppm\n\n{}", USAGE));
+    }
+    if options.scale == Some(0) {
+        return Err(format!("--scale must be at least 1\n\n{}", USAGE));
+    }
+
+    Ok(options)
+}
+
+/*
+ * Solves one part of a day while showing its intermediate states as an animation in the
+ * terminal, or writing them to numbered PPM images.
+ */
+fn visualize(options: &VisualizeOptions) -> Result<(), String> {
+    let solver = get_solver(options.day).ok_or(format!("Day {} has not been implemented", options.day))?;
+    if !(1..=solver.parts).contains(&options.part) {
+        return Err(format!("Day {} has no part {}", options.day, options.part));
+    }
+    let (input_name, input) = read_day_input(options.day, options.input_path.as_deref())?;
+
+    let mut sink: Box<dyn FrameSink> = match &options.ppm_dir {
+        Some(dir) => Box::new(
+            PpmSink::new(dir, options.scale.unwrap_or(4))
+                .map_err(|e| format!("Could not create the directory {}: {}", dir, e))?,
+        ),
+        None => Box::new(TerminalSink::new(io::stdout(), options.delay.unwrap_or(Duration::from_millis(100)))),
+    };
+
+    let visualized = solver
+        .visualize(&input, options.part, sink.as_mut())
+        .map_err(|e| e.in_file(&input_name).to_string())?;
+    sink.finish().map_err(|e| format!("Could not write the frames: {}", e))?;
+
+    if visualized {
+        Ok(())
+    } else {
+        Err(format!("Day {} part {} has no visualization", options.day, options.part))
+    }
 }
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/*
+ * Returns base^exp mod modulus.
+ */
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "The modulus must be positive");
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+pub fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a.abs()
+}
+
+/*
+ * Returns (g, x, y) such that g = gcd(a, b) and a * x + b * y = g.
+ */
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/*
+ * Returns the x in [0, modulus) for which a * x = 1 (mod modulus), or None if a and the
+ * modulus are not coprime.
+ */
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "The modulus must be positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/*
+ * The congruence x = residue (mod modulus).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    pub fn new(residue: i64, modulus: i64) -> Congruence {
+        Congruence { residue, modulus }
+    }
+}
+
+/*
+ * The reason a system of congruences has no solution. The index is the congruence that
+ * could not be combined with the ones before it.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    // The modulus is not positive
+    InvalidModulus(usize),
+    // The congruence contradicts the earlier ones
+    Contradiction(usize),
+    // The combined modulus does not fit in an i64
+    Overflow(usize),
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::InvalidModulus(idx) => write!(f, "congruence {} has a modulus below 1", idx + 1),
+            CrtError::Contradiction(idx) => write!(f, "congruence {} contradicts the earlier ones", idx + 1),
+            CrtError::Overflow(idx) => write!(f, "the moduli up to congruence {} overflow", idx + 1),
+        }
+    }
+}
+
+/*
+ * Solves a system of congruences with the Chinese remainder theorem. The moduli do not
+ * have to be coprime. Returns the solution as a single congruence whose modulus is the
+ * least common multiple of the moduli and whose residue is the smallest non-negative
+ * solution.
+ */
+pub fn solve_crt(congruences: &[Congruence]) -> Result<Congruence, CrtError> {
+    let (mut residue, mut modulus): (i128, i128) = (0, 1);
+    for (idx, congruence) in congruences.iter().enumerate() {
+        if congruence.modulus < 1 {
+            return Err(CrtError::InvalidModulus(idx));
+        }
+        let other_modulus = congruence.modulus as i128;
+        let other_residue = (congruence.residue as i128).rem_euclid(other_modulus);
+
+        // x = residue + modulus * k must also satisfy the new congruence, which has a
+        // solution for k only if gcd(modulus, other_modulus) divides the difference
+        let g = gcd(modulus as i64, other_modulus as i64) as i128;
+        let difference = other_residue - residue;
+        if difference % g != 0 {
+            return Err(CrtError::Contradiction(idx));
+        }
+        let reduced_modulus = other_modulus / g;
+        let inverse = match mod_inverse(((modulus / g) % reduced_modulus) as i64, reduced_modulus as i64) {
+            Some(inverse) => inverse as i128,
+            None => unreachable!("modulus / g and other_modulus / g are coprime"),
+        };
+        let k = (difference / g).rem_euclid(reduced_modulus) * inverse % reduced_modulus;
+
+        let combined_modulus = modulus * reduced_modulus;
+        if combined_modulus > i64::MAX as i128 {
+            return Err(CrtError::Overflow(idx));
+        }
+        residue = (residue + modulus * k).rem_euclid(combined_modulus);
+        modulus = combined_modulus;
+    }
+
+    Ok(Congruence::new(residue as i64, modulus as i64))
+}
+
+/*
+ * Returns the smallest x >= 0 for which base^x = target (mod modulus), or None if there is
+ * none. Uses the baby-step giant-step algorithm, so it takes about sqrt(modulus) steps and
+ * memory. It also returns None if the base is not coprime with the modulus, which the
+ * giant steps need.
+ */
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    assert!(modulus > 0, "The modulus must be positive");
+    let inverse = mod_inverse(base as i64, modulus as i64)?;
+    let target = target % modulus;
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    // Baby steps: base^j for every j < steps, keeping the smallest j of every value
+    let mut baby_steps: HashMap<u64, u64> = HashMap::new();
+    let mut value = 1 % modulus;
+    for j in 0..steps {
+        baby_steps.entry(value).or_insert(j);
+        value = (value as u128 * base as u128 % modulus as u128) as u64;
+    }
+
+    // Giant steps: target * base^(-steps * i) for every i
+    let giant_step = mod_pow(inverse as u64, steps, modulus);
+    let mut value = target;
+    for i in 0..steps {
+        if let Some(j) = baby_steps.get(&value) {
+            return Some(i * steps + j);
+        }
+        value = (value as u128 * giant_step as u128 % modulus as u128) as u64;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{discrete_log, extended_gcd, mod_inverse, mod_pow, solve_crt, Congruence, CrtError};
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(14897079, mod_pow(17807724, 8, 20201227));
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 6));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        let buses: [(i64, i64); 5] = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)];
+        let congruences: Vec<Congruence> = buses.iter().map(|(bus, idx)| Congruence::new(-idx, *bus)).collect();
+        assert_eq!(Ok(Congruence::new(1068781, 7 * 13 * 59 * 31 * 19)), solve_crt(&congruences));
+
+        // The moduli share a factor of 2
+        let congruences = [Congruence::new(2, 4), Congruence::new(4, 6)];
+        assert_eq!(Ok(Congruence::new(10, 12)), solve_crt(&congruences));
+
+        let congruences = [Congruence::new(1, 4), Congruence::new(3, 5), Congruence::new(2, 6)];
+        assert_eq!(Err(CrtError::Contradiction(2)), solve_crt(&congruences));
+        assert_eq!(Err(CrtError::InvalidModulus(0)), solve_crt(&[Congruence::new(1, 0)]));
+
+        let congruences = [Congruence::new(1, i64::MAX), Congruence::new(1, 2)];
+        assert_eq!(Err(CrtError::Overflow(1)), solve_crt(&congruences));
+        assert_eq!(Ok(Congruence::new(0, 1)), solve_crt(&[]));
+    }
+
+    #[test]
+    fn discrete_logarithms() {
+        assert_eq!(Some(8), discrete_log(7, 5764801, 20201227));
+        assert_eq!(Some(11), discrete_log(7, 17807724, 20201227));
+        assert_eq!(Some(0), discrete_log(3, 1, 7));
+        // The powers of 2 modulo 7 are only 1, 2 and 4
+        assert_eq!(None, discrete_log(2, 3, 7));
+        // The base is not coprime with the modulus
+        assert_eq!(None, discrete_log(6, 0, 9));
+        assert_eq!(Some(1), discrete_log(10, 3, 7));
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::Answer;
+
+/*
+ * The answer to one part of a day, with the time it took to parse the input and solve
+ * the part.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Unknown output format: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/*
+ * Formats the records as a JSON array with one object per record. The answers are
+ * strings so that large numbers keep their precision, and unsolved answers are null.
+ */
+pub fn to_json(records: &[ResultRecord]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|r| {
+            let answer = match &r.answer {
+                Answer::Unsolved => "null".to_string(),
+                answer => json_string(&answer.to_string()),
+            };
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"answer_type\": \"{}\", \"elapsed_ns\": {}}}",
+                r.day,
+                r.part,
+                answer,
+                r.answer.type_name(),
+                r.elapsed.as_nanos()
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+/*
+ * Formats the records as CSV with a header row. Unsolved answers are left empty.
+ */
+pub fn to_csv(records: &[ResultRecord]) -> String {
+    let mut rows = vec!["day,part,answer,answer_type,elapsed_ns".to_string()];
+    for r in records {
+        let answer = match &r.answer {
+            Answer::Unsolved => String::new(),
+            answer => csv_field(&answer.to_string()),
+        };
+        rows.push(format!(
+            "{},{},{},{},{}",
+            r.day,
+            r.part,
+            answer,
+            r.answer.type_name(),
+            r.elapsed.as_nanos()
+        ));
+    }
+    rows.join("\n")
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{to_csv, to_json, ResultRecord};
+    use crate::Answer;
+
+    fn records() -> Vec<ResultRecord> {
+        vec![
+            ResultRecord {
+                day: 21,
+                part: 2,
+                answer: Answer::Text("mxmxvkd,sqjhc,\"fvjkl\"".to_string()),
+                elapsed: Duration::from_micros(1500),
+            },
+            ResultRecord {
+                day: 25,
+                part: 1,
+                answer: Answer::Unsolved,
+                elapsed: Duration::from_nanos(42),
+            },
+        ]
+    }
+
+    #[test]
+    fn records_as_json() {
+        assert_eq!(
+            "[\n  {\"day\": 21, \"part\": 2, \"answer\": \"mxmxvkd,sqjhc,\\\"fvjkl\\\"\", \"answer_type\": \"text\", \"elapsed_ns\": 1500000},\n  {\"day\": 25, \"part\": 1, \"answer\": null, \"answer_type\": \"unsolved\", \"elapsed_ns\": 42}\n]",
+            to_json(&records())
+        );
+        assert_eq!("[]", to_json(&[]));
+    }
+
+    #[test]
+    fn records_as_csv() {
+        assert_eq!(
+            "day,part,answer,answer_type,elapsed_ns\n21,2,\"mxmxvkd,sqjhc,\"\"fvjkl\"\"\",text,1500000\n25,1,,unsolved,42",
+            to_csv(&records())
+        );
+    }
+}
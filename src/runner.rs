@@ -0,0 +1,154 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::report::ResultRecord;
+
+/*
+ * How solving a single day ended in a parallel run.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOutcome {
+    Solved(Vec<ResultRecord>),
+    // The day could not be solved, e.g. because its input was missing or malformed
+    Failed(String),
+    // The solver panicked with the given message
+    Panicked(String),
+    // The solver did not finish within the timeout
+    TimedOut(Duration),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub outcome: DayOutcome,
+}
+
+/*
+ * Solves the given days on a pool of `jobs` worker threads and returns the reports in
+ * the order of the days. Every day runs in a thread of its own, so a panic only fails
+ * that day. A day that runs longer than `timeout` is reported as timed out and its
+ * worker moves on to the next day. Threads cannot be cancelled, so the timed out solver
+ * keeps running in the background until the process exits.
+ */
+pub fn run_parallel<F>(days: &[u8], jobs: usize, timeout: Option<Duration>, solve_day: F) -> Vec<DayReport>
+where
+    F: Fn(u8) -> Result<Vec<ResultRecord>, String> + Send + Sync + 'static,
+{
+    assert!(jobs > 0, "At least one worker thread is needed");
+
+    let solve_day = Arc::new(solve_day);
+    let queue = Arc::new(Mutex::new(days.iter().copied().collect::<VecDeque<u8>>()));
+    let (report_tx, report_rx) = mpsc::channel();
+
+    let workers: Vec<thread::JoinHandle<()>> = (0..jobs.min(days.len()))
+        .map(|_| {
+            let solve_day = Arc::clone(&solve_day);
+            let queue = Arc::clone(&queue);
+            let report_tx = report_tx.clone();
+            thread::spawn(move || {
+                loop {
+                    // Release the lock before solving the day
+                    let next_day = queue.lock().unwrap().pop_front();
+                    let day = match next_day {
+                        Some(day) => day,
+                        None => break,
+                    };
+                    let outcome = run_with_timeout(day, Arc::clone(&solve_day), timeout);
+                    report_tx.send(DayReport { day, outcome }).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(report_tx);
+
+    let mut reports: Vec<DayReport> = report_rx.iter().collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    reports.sort_by_key(|r| days.iter().position(|d| *d == r.day));
+    reports
+}
+
+fn run_with_timeout<F>(day: u8, solve_day: Arc<F>, timeout: Option<Duration>) -> DayOutcome
+where
+    F: Fn(u8) -> Result<Vec<ResultRecord>, String> + Send + Sync + 'static,
+{
+    let (result_tx, result_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solve_day(day)));
+        // The receiver is gone if the day already timed out
+        let _ = result_tx.send(result);
+    });
+
+    let result = match timeout {
+        Some(timeout) => match result_rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => return DayOutcome::TimedOut(timeout),
+            Err(RecvTimeoutError::Disconnected) => return DayOutcome::Panicked(String::new()),
+        },
+        None => match result_rx.recv() {
+            Ok(result) => result,
+            Err(_) => return DayOutcome::Panicked(String::new()),
+        },
+    };
+
+    match result {
+        Ok(Ok(records)) => DayOutcome::Solved(records),
+        Ok(Err(message)) => DayOutcome::Failed(message),
+        Err(payload) => DayOutcome::Panicked(panic_message(payload.as_ref())),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{run_parallel, DayOutcome};
+    use crate::report::ResultRecord;
+    use crate::Answer;
+
+    fn solve_day(day: u8) -> Result<Vec<ResultRecord>, String> {
+        match day {
+            1 => thread::sleep(Duration::from_secs(5)),
+            2 => panic!("Day {} exploded", day),
+            3 => return Err("Could not read the input".to_string()),
+            _ => {}
+        }
+        Ok(vec![ResultRecord {
+            day,
+            part: 1,
+            answer: Answer::Unsigned(day as u64),
+            elapsed: Duration::from_millis(1),
+        }])
+    }
+
+    #[test]
+    fn reports_every_day_in_order() {
+        let days = [4, 1, 2, 3, 5];
+        let reports = run_parallel(&days, 2, Some(Duration::from_millis(200)), solve_day);
+
+        assert_eq!(vec![4, 1, 2, 3, 5], reports.iter().map(|r| r.day).collect::<Vec<u8>>());
+        assert!(matches!(&reports[0].outcome, DayOutcome::Solved(records) if records[0].answer == Answer::Unsigned(4)));
+        assert_eq!(DayOutcome::TimedOut(Duration::from_millis(200)), reports[1].outcome);
+        assert_eq!(DayOutcome::Panicked("Day 2 exploded".to_string()), reports[2].outcome);
+        assert_eq!(DayOutcome::Failed("Could not read the input".to_string()), reports[3].outcome);
+        assert!(matches!(reports[4].outcome, DayOutcome::Solved(_)));
+    }
+}
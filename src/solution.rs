@@ -0,0 +1,215 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseResult;
+use crate::visualization::FrameSink;
+
+/*
+ * Common interface for all the days. The input is parsed once into a typed
+ * representation, which both parts then solve independently.
+ */
+pub trait Solution {
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    // The number of parts in the day's puzzle
+    const PARTS: u8 = 2;
+
+    fn parse(input: &[String]) -> ParseResult<Self::Input>;
+
+    // Parses the raw input text. Days whose parsers want one string override this, the
+    // others parse the input row by row.
+    fn parse_text(input: &str) -> ParseResult<Self::Input> {
+        let rows: Vec<String> = input.lines().map(str::to_string).collect();
+        Self::parse(&rows)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    // Solves the part while emitting the intermediate states of its simulation to the
+    // sink. Returns false if the part has nothing to show.
+    fn visualize(_input: &Self::Input, _part: u8, _sink: &mut dyn FrameSink) -> bool {
+        false
+    }
+}
+
+/*
+ * A type-erased answer to one part of a puzzle.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    // The solver could not find an answer for the input
+    Unsolved,
+}
+
+impl Answer {
+    // The name of the answer's type in machine-readable output
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(v) => write!(f, "{}", v),
+            Answer::Signed(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(v) => v.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/*
+ * Runs the parts of one day without knowing the day's concrete types, so the days can
+ * be stored in a single registry.
+ */
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    pub parts: u8,
+    solve_fn: fn(&str, u8) -> ParseResult<Answer>,
+    solve_timed_fn: fn(&str) -> ParseResult<TimedAnswers>,
+    visualize_fn: fn(&str, u8, &mut dyn FrameSink) -> ParseResult<bool>,
+}
+
+/*
+ * The answers of all the parts of a day, with the time spent parsing the input and
+ * solving each part.
+ */
+#[derive(Debug, Clone)]
+pub struct TimedAnswers {
+    pub parse_time: Duration,
+    // The answer and the solving time of each part, in order
+    pub parts: Vec<(Answer, Duration)>,
+}
+
+impl Solver {
+    pub fn new<S: Solution>(day: u8) -> Solver {
+        Solver {
+            day,
+            parts: S::PARTS,
+            solve_fn: solve::<S>,
+            solve_timed_fn: solve_timed::<S>,
+            visualize_fn: visualize::<S>,
+        }
+    }
+
+    /*
+     * Parses the input and solves the given part. Panics if the part is not between 1 and
+     * `parts`.
+     */
+    pub fn solve(&self, input: &str, part: u8) -> ParseResult<Answer> {
+        assert!((1..=self.parts).contains(&part), "Day {} has no part {}", self.day, part);
+        (self.solve_fn)(input, part)
+    }
+
+    /*
+     * Parses the input once and solves all the parts, timing each step separately.
+     */
+    pub fn solve_timed(&self, input: &str) -> ParseResult<TimedAnswers> {
+        (self.solve_timed_fn)(input)
+    }
+
+    /*
+     * Parses the input and solves the given part, emitting its intermediate states to the
+     * sink. Returns false if the day cannot visualize the part. Panics if the part is not
+     * between 1 and `parts`.
+     */
+    pub fn visualize(&self, input: &str, part: u8, sink: &mut dyn FrameSink) -> ParseResult<bool> {
+        assert!((1..=self.parts).contains(&part), "Day {} has no part {}", self.day, part);
+        (self.visualize_fn)(input, part, sink)
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: u8) -> ParseResult<Answer> {
+    let parsed = S::parse_text(input)?;
+    match part {
+        1 => Ok(S::part1(&parsed).into()),
+        _ => Ok(S::part2(&parsed).into()),
+    }
+}
+
+fn solve_timed<S: Solution>(input: &str) -> ParseResult<TimedAnswers> {
+    let start = Instant::now();
+    let parsed = S::parse_text(input)?;
+    let parse_time = start.elapsed();
+
+    let mut parts = Vec::new();
+    for part in 1..=S::PARTS {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed).into(),
+            _ => S::part2(&parsed).into(),
+        };
+        parts.push((answer, start.elapsed()));
+    }
+
+    Ok(TimedAnswers { parse_time, parts })
+}
+
+fn visualize<S: Solution>(input: &str, part: u8, sink: &mut dyn FrameSink) -> ParseResult<bool> {
+    let parsed = S::parse_text(input)?;
+    Ok(S::visualize(&parsed, part, sink))
+}
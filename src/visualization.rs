@@ -0,0 +1,284 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+}
+
+/*
+ * One intermediate state of a simulation, drawn as a grid of colored cells. The caption
+ * describes the state, e.g. the round it was taken in.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    width: usize,
+    height: usize,
+    cells: Vec<Color>,
+}
+
+impl Frame {
+    pub fn new(caption: &str, width: usize, height: usize, background: Color) -> Frame {
+        Frame {
+            caption: caption.to_string(),
+            width,
+            height,
+            cells: vec![background; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        assert!(x < self.width && y < self.height, "<{},{}> is outside the frame", x, y);
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        assert!(x < self.width && y < self.height, "<{},{}> is outside the frame", x, y);
+        self.cells[y * self.width + x] = color;
+    }
+}
+
+/*
+ * Receives the frames of a simulation as it runs. Emitting cannot fail, so the solvers do
+ * not have to handle errors; a sink that fails to write keeps the first error, skips the
+ * later frames and returns the error from `finish`.
+ */
+pub trait FrameSink {
+    // Solvers skip drawing the frames if this is false
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn emit(&mut self, frame: Frame);
+
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/*
+ * Discards all the frames. The parts are solved with this sink, so they do not pay for
+ * drawing.
+ */
+pub struct NoFrames;
+
+impl FrameSink for NoFrames {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn emit(&mut self, _frame: Frame) {}
+}
+
+// Collects the frames in memory
+impl FrameSink for Vec<Frame> {
+    fn emit(&mut self, frame: Frame) {
+        self.push(frame);
+    }
+}
+
+/*
+ * Animates the frames in an ANSI terminal. Each character draws two cells on top of each
+ * other with a half block, which keeps the cells roughly square.
+ */
+pub struct TerminalSink<W: Write> {
+    out: W,
+    // How long each frame is shown before the next one is drawn
+    delay: Duration,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TerminalSink<W> {
+    pub fn new(out: W, delay: Duration) -> TerminalSink<W> {
+        TerminalSink { out, delay, error: None }
+    }
+
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        // Clear the screen and move the cursor to the top left corner
+        let mut text = String::from("\x1b[2J\x1b[H");
+        text.push_str(&frame.caption);
+        text.push('\n');
+        for y in (0..frame.height()).step_by(2) {
+            for x in 0..frame.width() {
+                let top = frame.get(x, y);
+                text.push_str(&format!("\x1b[38;2;{};{};{}m", top.r, top.g, top.b));
+                if y + 1 < frame.height() {
+                    let bottom = frame.get(x, y + 1);
+                    text.push_str(&format!("\x1b[48;2;{};{};{}m", bottom.r, bottom.g, bottom.b));
+                }
+                text.push('▀');
+            }
+            text.push_str("\x1b[0m\n");
+        }
+
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()
+    }
+}
+
+impl<W: Write> FrameSink for TerminalSink<W> {
+    fn emit(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.draw(&frame) {
+            Ok(()) => thread::sleep(self.delay),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+/*
+ * Writes every frame to a numbered binary PPM image (frame_00000.ppm, frame_00001.ppm, ...)
+ * in a directory, drawing each cell as a square of `scale` pixels. The caption is stored
+ * as a comment in the image header.
+ */
+pub struct PpmSink {
+    dir: PathBuf,
+    scale: usize,
+    frame_count: usize,
+    error: Option<io::Error>,
+}
+
+impl PpmSink {
+    /*
+     * Creates the directory if it does not exist yet.
+     */
+    pub fn new<P: Into<PathBuf>>(dir: P, scale: usize) -> io::Result<PpmSink> {
+        assert!(scale > 0, "The scale must be at least 1");
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(PpmSink { dir, scale, frame_count: 0, error: None })
+    }
+
+    pub fn frame_path(&self, idx: usize) -> PathBuf {
+        self.dir.join(format!("frame_{:05}.ppm", idx))
+    }
+
+    fn write(&self, frame: &Frame) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(self.frame_path(self.frame_count))?);
+        write_ppm(&mut out, frame, self.scale)?;
+        out.flush()
+    }
+}
+
+impl FrameSink for PpmSink {
+    fn emit(&mut self, frame: Frame) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.write(&frame) {
+            Ok(()) => self.frame_count += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+/*
+ * Encodes a frame as a binary PPM (P6) image.
+ */
+pub fn write_ppm<W: Write>(out: &mut W, frame: &Frame, scale: usize) -> io::Result<()> {
+    writeln!(out, "P6")?;
+    for line in frame.caption.lines() {
+        writeln!(out, "# {}", line)?;
+    }
+    writeln!(out, "{} {}\n255", frame.width() * scale, frame.height() * scale)?;
+
+    for y in 0..frame.height() * scale {
+        for x in 0..frame.width() * scale {
+            let color = frame.get(x / scale, y / scale);
+            out.write_all(&[color.r, color.g, color.b])?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{write_ppm, Color, Frame, FrameSink, TerminalSink};
+    use crate::day11::Day11;
+    use crate::Solution;
+
+    const RED: Color = Color::rgb(255, 0, 0);
+
+    fn checkered_frame() -> Frame {
+        let mut frame = Frame::new("Round 1", 2, 3, Color::BLACK);
+        frame.set(1, 0, RED);
+        frame.set(0, 2, Color::WHITE);
+        frame
+    }
+
+    #[test]
+    fn ppm_images_are_scaled() {
+        let mut image = Vec::new();
+        write_ppm(&mut image, &checkered_frame(), 2).unwrap();
+
+        let header = b"P6\n# Round 1\n4 6\n255\n";
+        assert_eq!(&header[..], &image[..header.len()]);
+        let pixels = &image[header.len()..];
+        assert_eq!(4 * 6 * 3, pixels.len());
+        // The second row of pixels still belongs to the first row of cells
+        assert_eq!(&[0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0], &pixels[12..24]);
+    }
+
+    #[test]
+    fn terminal_draws_two_rows_per_line() {
+        let mut out = Vec::new();
+        let mut sink = TerminalSink::new(&mut out, Duration::from_millis(0));
+        sink.emit(checkered_frame());
+        sink.finish().unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(3, lines.len());
+        assert!(lines[0].ends_with("Round 1"));
+        assert!(lines[1].contains("\x1b[38;2;255;0;0m\x1b[48;2;0;0;0m▀"));
+        assert_eq!(2, lines[2].matches('▀').count());
+    }
+
+    #[test]
+    fn simulations_emit_every_round() {
+        let input = include_str!("../input/examples/day11.txt");
+        let mut frames: Vec<Frame> = Vec::new();
+        assert!(Day11::visualize(&Day11::parse_text(input).unwrap(), 1, &mut frames));
+
+        // The initial layout, five rounds of changes and the round that changed nothing
+        assert_eq!(7, frames.len());
+        assert_eq!((10, 10), (frames[0].width(), frames[0].height()));
+        let last_frame = Frame { caption: "Round 5".to_string(), ..frames[6].clone() };
+        assert_eq!(frames[5], last_frame);
+    }
+}
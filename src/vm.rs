@@ -0,0 +1,279 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::error::{column_of, parse_token, ParseError, ParseResult};
+
+/*
+ * An instruction of the handheld game console.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    // Adds the argument to the accumulator
+    Acc(i32),
+    // Jumps relative to the instruction itself
+    Jmp(i32),
+    // Does nothing
+    Nop(i32),
+}
+
+impl Instruction {
+    /*
+     * Parses an instruction like "jmp -4" from the row at the given 0-based index.
+     */
+    pub fn parse(row: &str, row_idx: usize) -> ParseResult<Instruction> {
+        let mut parts = row.split(' ');
+        let op = parts.next().unwrap_or_default();
+        let arg = match parts.next() {
+            Some(arg) => parse_token::<i32>(arg, row_idx, column_of(row, arg), "a signed argument")?,
+            None => return Err(ParseError::end_of_input(row_idx, row.chars().count(), "an argument")),
+        };
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::new(row_idx, column_of(row, extra), "end of the instruction", extra));
+        }
+
+        match op {
+            "acc" => Ok(Instruction::Acc(arg)),
+            "jmp" => Ok(Instruction::Jmp(arg)),
+            "nop" => Ok(Instruction::Nop(arg)),
+            &_ => Err(ParseError::new(row_idx, 0, "\"acc\", \"jmp\" or \"nop\"", op)),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Acc(arg) => write!(f, "acc {:+}", arg),
+            Instruction::Jmp(arg) => write!(f, "jmp {:+}", arg),
+            Instruction::Nop(arg) => write!(f, "nop {:+}", arg),
+        }
+    }
+}
+
+/*
+ * Assembles a program from its source text, one instruction per line. Blank lines and
+ * comments starting with '#' are skipped, so error positions refer to the source text.
+ */
+pub fn assemble(source: &str) -> ParseResult<Vec<Instruction>> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(row_idx, row)| {
+            let code = match row.find('#') {
+                Some(idx) => &row[..idx],
+                None => row,
+            };
+            let code = code.trim_end();
+            match code.is_empty() {
+                true => None,
+                false => Some(Instruction::parse(code, row_idx)),
+            }
+        })
+        .collect()
+}
+
+/*
+ * Turns a program back into source text that assembles to the same program.
+ */
+pub fn disassemble(program: &[Instruction]) -> String {
+    program.iter().map(|instruction| format!("{}\n", instruction)).collect()
+}
+
+/*
+ * Why a machine stopped running.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // The program counter points right after the last instruction
+    Halted,
+    // The program counter points anywhere else outside the program
+    OutOfBounds,
+    // The next instruction has already been executed once
+    Loop,
+    // The maximum number of executed instructions was reached
+    StepLimit,
+    // The next instruction would overflow the accumulator
+    Overflow,
+}
+
+/*
+ * The conditions besides leaving the program that stop a running machine.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Termination {
+    // Stop before an instruction would be executed for the second time
+    pub detect_loops: bool,
+    // Stop once the instruction counter reaches this value
+    pub max_steps: Option<u64>,
+}
+
+impl Default for Termination {
+    fn default() -> Self {
+        Termination {
+            detect_loops: true,
+            max_steps: None,
+        }
+    }
+}
+
+pub struct Machine {
+    program: Vec<Instruction>,
+    // The index of the next instruction, which may point outside the program
+    pc: i64,
+    accumulator: i32,
+    // The number of instructions executed so far
+    steps: u64,
+}
+
+impl Machine {
+    pub fn new(program: Vec<Instruction>) -> Machine {
+        Machine {
+            program,
+            pc: 0,
+            accumulator: 0,
+            steps: 0,
+        }
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn pc(&self) -> i64 {
+        self.pc
+    }
+
+    pub fn accumulator(&self) -> i32 {
+        self.accumulator
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    // The next instruction to execute, if the program counter is inside the program
+    pub fn current(&self) -> Option<Instruction> {
+        usize::try_from(self.pc).ok().and_then(|pc| self.program.get(pc).copied())
+    }
+
+    /*
+     * Puts the machine back in its initial state, keeping the program.
+     */
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.accumulator = 0;
+        self.steps = 0;
+    }
+
+    /*
+     * Executes the next instruction. Returns why the machine cannot continue instead if
+     * the program counter points outside the program or the instruction would overflow
+     * the accumulator, leaving the machine unchanged.
+     */
+    pub fn step(&mut self) -> Result<(), Stop> {
+        let instruction = match self.current() {
+            Some(instruction) => instruction,
+            None if self.pc == self.program.len() as i64 => return Err(Stop::Halted),
+            None => return Err(Stop::OutOfBounds),
+        };
+        match instruction {
+            Instruction::Acc(arg) => {
+                self.accumulator = self.accumulator.checked_add(arg).ok_or(Stop::Overflow)?;
+                self.pc += 1;
+            }
+            Instruction::Jmp(arg) => self.pc += arg as i64,
+            Instruction::Nop(_) => self.pc += 1,
+        }
+        self.steps += 1;
+        Ok(())
+    }
+
+    /*
+     * Keeps executing instructions until the program is left or one of the termination
+     * conditions is met. Loops are detected from the point where the run starts.
+     */
+    pub fn run(&mut self, termination: &Termination) -> Stop {
+        let mut visited = vec![false; self.program.len()];
+        loop {
+            if termination.max_steps.is_some_and(|max_steps| self.steps >= max_steps) {
+                return Stop::StepLimit;
+            }
+            if let Ok(pc) = usize::try_from(self.pc) {
+                if termination.detect_loops && pc < visited.len() {
+                    if visited[pc] {
+                        return Stop::Loop;
+                    }
+                    visited[pc] = true;
+                }
+            }
+            if let Err(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assemble, disassemble, Instruction, Machine, Stop, Termination};
+
+    const PROGRAM: &str = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+    #[test]
+    fn assembly_round_trips() {
+        let program = assemble(PROGRAM).unwrap();
+        assert_eq!(9, program.len());
+        assert_eq!(Instruction::Jmp(-3), program[4]);
+        assert_eq!(PROGRAM, disassemble(&program));
+
+        let program = assemble("# Setup\nacc +1  # increment\n\njmp -1\n").unwrap();
+        assert_eq!(vec![Instruction::Acc(1), Instruction::Jmp(-1)], program);
+
+        let error = assemble("nop +0\n\njmp 1x\n").err().unwrap();
+        assert_eq!((3, 5), (error.line, error.column));
+    }
+
+    #[test]
+    fn machines_step_through_programs() {
+        let mut machine = Machine::new(assemble(PROGRAM).unwrap());
+        assert_eq!(Some(Instruction::Nop(0)), machine.current());
+        for _ in 0..3 {
+            machine.step().unwrap();
+        }
+        assert_eq!((6, 1, 3), (machine.pc(), machine.accumulator(), machine.steps()));
+
+        assert_eq!(Stop::Loop, machine.run(&Termination::default()));
+        assert_eq!((6, 6, 9), (machine.pc(), machine.accumulator(), machine.steps()));
+
+        machine.reset();
+        let termination = Termination {
+            detect_loops: false,
+            max_steps: Some(100),
+        };
+        assert_eq!(Stop::StepLimit, machine.run(&termination));
+        assert_eq!(100, machine.steps());
+    }
+
+    #[test]
+    fn machines_stop_outside_the_program() {
+        let mut machine = Machine::new(assemble("acc +2\nnop +0\n").unwrap());
+        assert_eq!(Stop::Halted, machine.run(&Termination::default()));
+        assert_eq!(Err(Stop::Halted), machine.step());
+        assert_eq!(2, machine.accumulator());
+
+        let mut machine = Machine::new(assemble("nop +0\njmp -2\n").unwrap());
+        assert_eq!(Stop::OutOfBounds, machine.run(&Termination::default()));
+        assert_eq!(-1, machine.pc());
+    }
+
+    #[test]
+    fn machines_stop_on_overflow() {
+        let mut machine = Machine::new(assemble("acc +2147483647\njmp -1\n").unwrap());
+        let termination = Termination {
+            detect_loops: false,
+            max_steps: Some(100),
+        };
+        assert_eq!(Stop::Overflow, machine.run(&termination));
+        assert_eq!((0, i32::MAX, 2), (machine.pc(), machine.accumulator(), machine.steps()));
+    }
+}